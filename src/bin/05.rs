@@ -1,15 +1,11 @@
-use petgraph::{
-    dot::{Config, Dot},
-    graph::NodeIndex,
-    Direction::Outgoing,
-    Graph,
-};
+use advent_of_code::template::viz;
+use petgraph::{graph::NodeIndex, Direction::Outgoing, Graph};
 use std::collections::{HashMap, VecDeque};
 use std::error::Error;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse_input);
 
-struct Problem {
+pub struct Problem {
     sequences: Vec<Vec<u32>>,
     g: Graph<u32, i32>,
     nodes: HashMap<u32, NodeIndex>,
@@ -27,9 +23,6 @@ fn parse_input(input: &str) -> Result<Problem, Box<dyn Error>> {
         let line = line.trim().to_string();
         if line.is_empty() {
             reading_sequences = true;
-            if cfg!(debug_assertions) {
-                println!("{:?}", Dot::with_config(&g, &[Config::EdgeNoLabel]));
-            }
             continue;
         }
         if !reading_sequences {
@@ -102,12 +95,31 @@ fn topological_sort(
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+/// Emit the rules as a matrix of the pages sorted by number, `#` if page `y` has to be printed before page `x`.
+fn dump_rules(p: &Problem) {
+    if !viz::enabled() {
+        return;
+    }
+
+    let mut pages: Vec<u32> = p.nodes.keys().copied().collect();
+    pages.sort_unstable();
+
+    viz::frame(pages.len(), pages.len(), |x, y| {
+        if p.g.contains_edge(p.nodes[&pages[y]], p.nodes[&pages[x]]) {
+            '#'
+        } else {
+            '.'
+        }
+    });
+}
+
+pub fn part_one(p: &Problem) -> Option<u32> {
+    dump_rules(p);
+
     let mut sum = 0;
-    let p = parse_input(input).unwrap();
-    for sequence in p.sequences {
-        if let Some(sorted) = topological_sort(&p.g, &p.nodes, &sequence) {
-            if sorted == sequence {
+    for sequence in &p.sequences {
+        if let Some(sorted) = topological_sort(&p.g, &p.nodes, sequence) {
+            if sorted == *sequence {
                 sum += sorted[sorted.len() / 2];
             }
         }
@@ -115,12 +127,11 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(sum)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(p: &Problem) -> Option<u32> {
     let mut sum = 0;
-    let p = parse_input(input).unwrap();
-    for sequence in p.sequences {
-        if let Some(sorted) = topological_sort(&p.g, &p.nodes, &sequence) {
-            if sorted != sequence {
+    for sequence in &p.sequences {
+        if let Some(sorted) = topological_sort(&p.g, &p.nodes, sequence) {
+            if sorted != *sequence {
                 sum += sorted[sorted.len() / 2];
             }
         }
@@ -134,13 +145,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_one(&parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(143));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_two(&parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(123));
     }
 }
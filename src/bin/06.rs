@@ -1,7 +1,7 @@
 advent_of_code::solution!(6);
 
 use std::collections::HashSet;
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 enum Dir {
     North,
     East,
@@ -117,26 +117,22 @@ pub fn part_one(input: &str) -> Option<u32> {
     None
 }
 
-fn count_visited(g: &Grid, p: Pos, d: Dir) -> i32 {
-    let mut visited = 0;
+/// Whether the guard walks in a loop, i.e. turns at the same position in the same direction twice.
+fn is_loop(g: &Grid, p: Pos, d: Dir) -> bool {
+    let mut turns = HashSet::new();
     let mut p = p;
     let mut direction = d;
-    while g.is_valid(p) {
-        if visited > 10000 {
-            break;
-        }
-        if let Some(next) = g.get(p, direction) {
-            if g.occupied(next) {
-                direction = direction.right();
-            } else {
-                p = next;
-                visited += 1;
+    while let Some(next) = g.get(p, direction) {
+        if g.occupied(next) {
+            if !turns.insert((p, direction)) {
+                return true;
             }
+            direction = direction.right();
         } else {
-            break;
+            p = next;
         }
     }
-    visited
+    false
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
@@ -167,7 +163,7 @@ pub fn part_two(input: &str) -> Option<u32> {
             let mut sum = 0;
             for &pos in visited.iter() {
                 g.block(pos);
-                if count_visited(&g, p, Dir::North) > 10000 {
+                if is_loop(&g, p, Dir::North) {
                     sum += 1;
                 }
                 g.unblock(pos);
@@ -1,8 +1,8 @@
 use std::error::Error;
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, parse = parse_input);
 
 // 3267: 81 40 27
-struct Equation {
+pub struct Equation {
     result: u128,
     inputs: Vec<u128>,
 }
@@ -41,8 +41,7 @@ fn parse_input(input: &str) -> Result<Vec<Equation>, Box<dyn Error>> {
     Ok(equations)
 }
 
-pub fn part_one(input: &str) -> Option<u128> {
-    let equations = parse_input(input).unwrap();
+pub fn part_one(equations: &[Equation]) -> Option<u128> {
     let mut result = 0;
 
     for equation in equations {
@@ -63,8 +62,7 @@ pub fn part_one(input: &str) -> Option<u128> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u128> {
-    let equations = parse_input(input).unwrap();
+pub fn part_two(equations: &[Equation]) -> Option<u128> {
     let mut result = 0;
 
     for equation in equations {
@@ -95,13 +93,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_one(&parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(3749));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_two(&parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(11387));
     }
 }
@@ -21,12 +21,14 @@ fn parse_input_for_part_one(input: &str) -> Vec<Node> {
         .enumerate()
         .flat_map(|(i, c)| {
             let count = c.to_digit(10).unwrap() as usize;
-            iter::repeat(match i % 2 {
-                0 => Node::File(File { id: i as u64 / 2 }),
-                1 => Node::Space,
-                _ => unreachable!(),
-            })
-            .take(count)
+            iter::repeat_n(
+                match i % 2 {
+                    0 => Node::File(File { id: i as u64 / 2 }),
+                    1 => Node::Space,
+                    _ => unreachable!(),
+                },
+                count,
+            )
         })
         .collect::<Vec<_>>()
 }
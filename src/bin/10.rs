@@ -103,7 +103,7 @@ impl<'a> Iterator for GridIter<'a> {
 }
 
 impl Grid {
-    fn iter(&self) -> GridIter {
+    fn iter(&self) -> GridIter<'_> {
         GridIter {
             grid: self,
             x: 0,
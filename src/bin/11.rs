@@ -9,7 +9,7 @@ fn blink(n: u64) -> Vec<u64> {
         return vec![1];
     }
     let s = n.to_string();
-    if s.len() % 2 == 0 {
+    if s.len().is_multiple_of(2) {
         vec![
             s[0..s.len() / 2].parse::<u64>().unwrap(),
             s[s.len() / 2..].parse::<u64>().unwrap(),
@@ -30,9 +30,7 @@ pub fn part_one(input: &str) -> Option<u64> {
         let new_nums = nums.iter().flat_map(|n| blink(*n)).collect::<Vec<u64>>();
         nums = new_nums;
     }
-    if cfg!(debug_assertions) {
-        println!("{}", nums.iter().unique().count());
-    }
+    advent_of_code::debug!("{} unique stones", nums.iter().unique().count());
     Some(nums.len() as u64)
 }
 
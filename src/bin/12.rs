@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(12, parse = parse_input);
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 enum Dir {
@@ -43,7 +43,7 @@ impl Pos {
     }
 }
 
-struct Grid(Vec<Vec<u8>>);
+pub struct Grid(Vec<Vec<u8>>);
 impl Grid {
     fn yln(&self) -> usize {
         self.0.len()
@@ -96,9 +96,12 @@ impl Grid {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+fn parse_input(input: &str) -> Option<Grid> {
     let grid: Vec<Vec<u8>> = input.lines().map(|l| l.as_bytes().to_vec()).collect();
-    let g = Grid(grid);
+    Some(Grid(grid))
+}
+
+pub fn part_one(g: &Grid) -> Option<u32> {
     let mut sum = 0;
     let mut visited = HashSet::new();
     for y in 0..g.yln() {
@@ -128,9 +131,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(sum)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let grid: Vec<Vec<u8>> = input.lines().map(|l| l.as_bytes().to_vec()).collect();
-    let g = Grid(grid);
+pub fn part_two(g: &Grid) -> Option<u32> {
     let mut sum = 0;
     let mut visited = HashSet::new();
     for y in 0..g.yln() {
@@ -213,13 +214,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_one(&parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(1930));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_two(&parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(1206));
     }
+
+    advent_of_code::example_tests!(parse = parse_input);
 }
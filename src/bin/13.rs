@@ -1,4 +1,4 @@
-advent_of_code::solution!(13);
+advent_of_code::solution!(13, parse = parse_input);
 
 #[derive(Debug, Clone, Copy)]
 struct Pos {
@@ -7,14 +7,15 @@ struct Pos {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Machine {
+pub struct Machine {
     a: Pos,
     b: Pos,
     prize: Pos,
 }
 
 fn get_pos(input: &str, sep: &str) -> Option<Pos> {
-    input.split_once(": ")
+    input
+        .split_once(": ")
         .and_then(|(_, input)| input.split_once(", "))
         .and_then(|(x, y)| {
             let x = x.split(sep).nth(1)?.parse().ok()?;
@@ -60,26 +61,25 @@ fn solve_buttons(m: &Machine) -> Option<(i64, i64)> {
     Some((x, y))
 }
 
-pub fn part_one(input: &str) -> Option<i64> {
-    let machines = parse_input(input).unwrap();
+pub fn part_one(machines: &[Machine]) -> Option<i64> {
     let mut sum = 0;
     for machine in machines {
-        if let Some((x, y)) = solve_buttons(&machine) {
+        if let Some((x, y)) = solve_buttons(machine) {
             sum += x * 3 + y;
             assert!(x + y <= 200);
             // lol
-            // You estimate that each button would need to be pressed *no more 
-            //than `100` times* to win a prize. How else would someone be 
+            // You estimate that each button would need to be pressed *no more
+            //than `100` times* to win a prize. How else would someone be
             // expected to play?
         }
     }
     Some(sum)
 }
 
-pub fn part_two(input: &str) -> Option<i64> {
-    let machines = parse_input(input).unwrap();
+pub fn part_two(machines: &[Machine]) -> Option<i64> {
     let mut sum = 0;
-    for mut machine in machines {
+    for &machine in machines {
+        let mut machine = machine;
         machine.prize.x += 10000000000000;
         machine.prize.y += 10000000000000;
         if let Some((x, y)) = solve_buttons(&machine) {
@@ -95,13 +95,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_one(&parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(480));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_two(&parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(875318608908));
     }
 }
@@ -4,6 +4,8 @@ use std::{
     cmp::Ordering::{Greater, Less},
 };
 
+use advent_of_code::template::{params::param, viz};
+
 advent_of_code::solution!(14);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -65,8 +67,16 @@ fn parse_input(input: &str) -> Vec<Robot> {
         .collect()
 }
 
-fn quadrant<const HEIGHT: isize, const WIDTH: isize>(pos: Pos) -> Option<Pos> {
-    match (pos.x.cmp(&(WIDTH / 2)), pos.y.cmp(&(HEIGHT / 2))) {
+/// The size of the grid, the example uses `width=11` and `height=7`.
+fn bounds() -> Pos {
+    Pos {
+        x: param("width", 101),
+        y: param("height", 103),
+    }
+}
+
+fn quadrant(pos: Pos, bounds: Pos) -> Option<Pos> {
+    match (pos.x.cmp(&(bounds.x / 2)), pos.y.cmp(&(bounds.y / 2))) {
         (Less, Less) => Some(Pos { x: 0, y: 0 }),
         (Less, Greater) => Some(Pos { x: 0, y: 1 }),
         (Greater, Less) => Some(Pos { x: 1, y: 0 }),
@@ -77,42 +87,30 @@ fn quadrant<const HEIGHT: isize, const WIDTH: isize>(pos: Pos) -> Option<Pos> {
 
 pub fn part_one(input: &str) -> Option<u32> {
     let robots = parse_input(input);
-    const WIDTH: isize = 101;
-    const HEIGHT: isize = 103;
+    let bounds = bounds();
     let mut quads = HashMap::new();
     for mut robot in robots {
         for _ in 0..100 {
-            robot.pos = (robot.pos + robot.speed).wrapping_add(Pos {
-                x: WIDTH,
-                y: HEIGHT,
-            });
+            robot.pos = (robot.pos + robot.speed).wrapping_add(bounds);
         }
-        if let Some(pos) = quadrant::<HEIGHT, WIDTH>(robot.pos) {
+        if let Some(pos) = quadrant(robot.pos, bounds) {
             quads.entry(pos).or_insert(Vec::new()).push(robot);
         }
     }
     Some(quads.values().map(|v| v.len() as u32).product::<u32>())
 }
 
-fn dump_robots(uniq: &HashSet<Pos>) {
-    if !cfg!(debug_assertions) {
-        return;
-    }
-    const WIDTH: isize = 101;
-    const HEIGHT: isize = 103;
-    for y in 0..HEIGHT {
-        for x in 0..WIDTH {
-            if uniq.contains(&Pos { x, y }) {
-                print!("#");
-            } else {
-                print!(".");
-            }
+fn dump_robots(uniq: &HashSet<Pos>, bounds: Pos) {
+    viz::frame(bounds.x as usize, bounds.y as usize, |x, y| {
+        if uniq.contains(&Pos { x: x as isize, y: y as isize }) {
+            '#'
+        } else {
+            '.'
         }
-        println!();
-    }
+    });
 }
 
-fn max_scc_size<const HEIGHT: isize, const WIDTH: isize>(uniq: &HashSet<Pos>) -> u32 {
+fn max_scc_size(uniq: &HashSet<Pos>, bounds: Pos) -> u32 {
     let mut max = 0;
     let mut visited = HashSet::new();
     for p in uniq {
@@ -132,10 +130,7 @@ fn max_scc_size<const HEIGHT: isize, const WIDTH: isize>(uniq: &HashSet<Pos>) ->
                     Pos { x: 1, y: 0 },
                     Pos { x: -1, y: 0 },
                 ] {
-                    let n = (p + d).wrapping_add(Pos {
-                        x: WIDTH,
-                        y: HEIGHT,
-                    });
+                    let n = (p + d).wrapping_add(bounds);
                     if !uniq.contains(&n) {
                         continue;
                     }
@@ -150,24 +145,19 @@ fn max_scc_size<const HEIGHT: isize, const WIDTH: isize>(uniq: &HashSet<Pos>) ->
 
 pub fn part_two(input: &str) -> Option<u32> {
     let mut robots = parse_input(input);
-    const WIDTH: isize = 101;
-    const HEIGHT: isize = 103;
+    let bounds = bounds();
     for i in 1.. {
         robots.iter_mut().for_each(|robot| {
-            robot.pos = (robot.pos + robot.speed).wrapping_add(Pos {
-                x: WIDTH,
-                y: HEIGHT,
-            });
+            robot.pos = (robot.pos + robot.speed).wrapping_add(bounds);
         });
         let uniq = robots.iter().map(|r| r.pos).collect::<HashSet<_>>();
-        // dump_robots(&uniq);
-        // println!("{}: {}", i, max_scc_size::<HEIGHT, WIDTH>(&uniq));
+        dump_robots(&uniq, bounds);
+        advent_of_code::trace!("{i}: largest cluster of {}", max_scc_size(&uniq, bounds));
         if uniq.len() == robots.len() {
-            dump_robots(&uniq);  
             return Some(i as u32);
         }
-        // if max_scc_size::<HEIGHT, WIDTH>(&uniq) > 100 {
-        //     dump_robots(&uniq);
+        // if max_scc_size(&uniq, bounds) > 100 {
+        //     dump_robots(&uniq, bounds);
         //     return Some(i as u32);
         // }
     }
@@ -177,16 +167,26 @@ pub fn part_two(input: &str) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::params::with_params;
+
+    const EXAMPLE_BOUNDS: [(&str, &str); 2] = [("width", "11"), ("height", "7")];
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1));
+        let result = with_params(&EXAMPLE_BOUNDS, || {
+            part_one(&advent_of_code::template::read_file("examples", DAY))
+        });
+        assert_eq!(result, Some(12));
     }
 
     #[test]
+    #[ignore = "the puzzle has no example for part two"]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = with_params(&EXAMPLE_BOUNDS, || {
+            part_two(&advent_of_code::template::read_file("examples", DAY))
+        });
         assert_eq!(result, Some(1));
     }
+
+    advent_of_code::example_tests!(1);
 }
@@ -0,0 +1,14 @@
+// Collects all days into one binary that `cargo all` and `cargo time` run days through.
+// New days are added by `cargo scaffold`.
+advent_of_code::registry! {
+    day_05: "05.rs",
+    day_06: "06.rs",
+    day_07: "07.rs",
+    day_08: "08.rs",
+    day_09: "09.rs",
+    day_10: "10.rs",
+    day_11: "11.rs",
+    day_12: "12.rs",
+    day_13: "13.rs",
+    day_14: "14.rs",
+}
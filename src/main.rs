@@ -1,4 +1,6 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, answer, download, example, read, scaffold, solve, time, verify,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,13 +9,27 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::time,
+        runner::{BenchConfig, DEFAULT_TIMEOUT},
+        Day,
+    };
+    use std::{process, thread, time::Duration};
 
     pub enum AppArguments {
+        Answer {
+            day: Day,
+            part: u8,
+            answer: String,
+        },
         Download {
             day: Day,
         },
+        Example {
+            day: Day,
+            pick: Option<usize>,
+            overwrite: bool,
+        },
         Read {
             day: Day,
         },
@@ -27,14 +43,40 @@ mod args {
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            force: bool,
+            wait: bool,
+            viz: bool,
+            viz_format: Option<String>,
+            viz_fps: Option<u32>,
+            verbosity: u8,
+            log: bool,
+            params: Vec<String>,
+            timeout: Option<Duration>,
+            input: Option<String>,
+            example: Option<Option<usize>>,
         },
         All {
+            year: Option<u16>,
             release: bool,
+            timeout: Option<Duration>,
+            jobs: usize,
         },
         Time {
             all: bool,
             day: Option<Day>,
+            year: Option<u16>,
             store: bool,
+            bench: BenchConfig,
+            timeout: Option<Duration>,
+            compare: Option<f64>,
+        },
+        TimeHistory {
+            day: Day,
+        },
+        Verify {
+            year: Option<u16>,
+            timeout: Option<Duration>,
+            jobs: usize,
         },
         #[cfg(feature = "today")]
         Today,
@@ -45,21 +87,56 @@ mod args {
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: args.opt_value_from_str("--year")?,
                 release: args.contains("--release"),
+                timeout: multi_timeout(&mut args)?,
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+            },
+            Some("time") if args.contains("--history") => AppArguments::TimeHistory {
+                day: args.free_from_str()?,
             },
             Some("time") => {
                 let all = args.contains("--all");
+                let year = args.opt_value_from_str("--year")?;
                 let store = args.contains("--store");
+                let bench = BenchConfig {
+                    samples: args.opt_value_from_str("--samples")?,
+                    duration: args
+                        .opt_value_from_str("--bench-duration")?
+                        .map_or(BenchConfig::default().duration, Duration::from_millis),
+                    heap: args.contains("--heap"),
+                    perf: args.contains("--perf"),
+                };
+
+                let timeout = multi_timeout(&mut args)?;
+                let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+                let compare = args
+                    .contains("--compare")
+                    .then(|| threshold.unwrap_or(time::DEFAULT_REGRESSION_THRESHOLD));
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
+                    year,
                     store,
+                    bench,
+                    timeout,
+                    compare,
                 }
             }
+            Some("answer") => AppArguments::Answer {
+                day: args.free_from_str()?,
+                part: args.free_from_str()?,
+                answer: args.free_from_str()?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
+            Some("example") => AppArguments::Example {
+                day: args.free_from_str()?,
+                overwrite: args.contains("--overwrite"),
+                pick: args.opt_free_from_str()?,
+            },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
@@ -72,7 +149,37 @@ mod args {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
+                force: args.contains("--force"),
+                wait: args.contains("--wait"),
+                viz: args.contains("--viz"),
+                viz_format: args.opt_value_from_str("--viz-format")?,
+                viz_fps: args.opt_value_from_str("--viz-fps")?,
+                verbosity: if args.contains("-vv") {
+                    2
+                } else {
+                    u8::from(args.contains("-v"))
+                },
+                log: args.contains("--log"),
+                params: args.values_from_str("--param")?,
                 dhat: args.contains("--dhat"),
+                timeout: args
+                    .opt_value_from_str("--timeout")?
+                    .map(Duration::from_secs),
+                input: args.opt_value_from_str("--input")?,
+                // NOTE: the example number is a free argument, it has to be read after all other options.
+                example: if args.contains("--example") {
+                    Some(args.opt_free_from_str()?)
+                } else {
+                    None
+                },
+            },
+            Some("verify") => AppArguments::Verify {
+                year: args.opt_value_from_str("--year")?,
+                timeout: multi_timeout(&mut args)?,
+                // NOTE: verify runs on all cores by default, it does not print timings.
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or_else(|| {
+                    thread::available_parallelism().map_or(1, std::num::NonZero::get)
+                }),
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -93,6 +200,18 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Read `--timeout` (in seconds) of the commands that run many days, which default to [`DEFAULT_TIMEOUT`].
+    /// `--timeout 0` disables the timeout.
+    fn multi_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, pico_args::Error> {
+        Ok(match args.opt_value_from_str("--timeout")? {
+            None => Some(DEFAULT_TIMEOUT),
+            Some(0) => None,
+            Some(secs) => Some(Duration::from_secs(secs)),
+        })
+    }
 }
 
 fn main() {
@@ -102,9 +221,34 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All {
+                year,
+                release,
+                timeout,
+                jobs,
+            } => all::handle(year, release, timeout, jobs),
+            AppArguments::Time {
+                day,
+                year,
+                all,
+                store,
+                bench,
+                timeout,
+                compare,
+            } => time::handle(day, year, all, store, bench, timeout, compare),
+            AppArguments::TimeHistory { day } => time::handle_history(day),
+            AppArguments::Verify {
+                year,
+                timeout,
+                jobs,
+            } => verify::handle(year, timeout, jobs),
+            AppArguments::Answer { day, part, answer } => answer::handle(day, part, &answer),
             AppArguments::Download { day } => download::handle(day),
+            AppArguments::Example {
+                day,
+                pick,
+                overwrite,
+            } => example::handle(day, pick, overwrite),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
                 day,
@@ -121,7 +265,39 @@ fn main() {
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                force,
+                wait,
+                viz,
+                viz_format,
+                viz_fps,
+                verbosity,
+                log,
+                params,
+                timeout,
+                input,
+                example,
+            } => {
+                let submit = submit.map(|part| solve::Submit { part, force, wait });
+                // NOTE: a format or frame rate implies `--viz`.
+                let viz =
+                    (viz || viz_format.is_some() || viz_fps.is_some()).then_some(solve::Viz {
+                        format: viz_format,
+                        fps: viz_fps,
+                    });
+                // NOTE: `--log` implies `-v`.
+                let verbosity = if log { verbosity.max(1) } else { verbosity };
+                let options = solve::Options {
+                    submit,
+                    viz,
+                    verbosity,
+                    log,
+                    params,
+                    timeout,
+                    input,
+                    example,
+                };
+                solve::handle(day, release, dhat, options);
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
@@ -132,8 +308,8 @@ fn main() {
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run between the 1st of december and \
+                            the last day of the event. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,161 @@
+/// Module that stores the accepted answers of a day in `data/answers/{day}.json`.
+/// Answers are recorded when a submission is accepted or set via `cargo answer`, and every run is checked against them.
+use std::{collections::HashMap, fs, io, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+static ANSWERS_DIR_PATH: &str = "./data/answers";
+
+/// Known answers of a single day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Outcome of checking an answer against the stored one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerStatus {
+    Correct,
+    Incorrect { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    /// Read the answers of a day. If not present or malformed, returns no answers.
+    pub fn read_from_file(day: Day) -> Self {
+        fs::read_to_string(get_path(day))
+            .map_err(|e| e.to_string())
+            .and_then(|content| Answers::try_from(content.as_str()))
+            .unwrap_or_default()
+    }
+
+    pub fn store_file(&self, day: Day) -> Result<(), io::Error> {
+        let path = get_path(day);
+        // NOTE: days of other events are placed in a folder per year.
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_1 = Some(answer),
+            2 => self.part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    /// Compare an answer against the stored answer of a part.
+    pub fn check(&self, part: u8, answer: &str) -> AnswerStatus {
+        match self.get(part) {
+            Some(expected) if expected == answer.trim() => AnswerStatus::Correct,
+            Some(expected) => AnswerStatus::Incorrect {
+                expected: expected.to_string(),
+            },
+            None => AnswerStatus::Unknown,
+        }
+    }
+}
+
+/// Store the answer of a part, keeping the answer of the other part.
+pub fn store_answer(day: Day, part: u8, answer: &str) -> Result<String, io::Error> {
+    let mut answers = Answers::read_from_file(day);
+    answers.set(part, answer.trim().to_string());
+    answers.store_file(day)?;
+    Ok(get_path(day))
+}
+
+fn get_path(day: Day) -> String {
+    format!("{ANSWERS_DIR_PATH}/{day}.json")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let optional = |v: &Option<String>| v.clone().map_or(JsonValue::Null, JsonValue::String);
+
+        map.insert("part_1".into(), optional(&value.part_1));
+        map.insert("part_2".into(), optional(&value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for Answers {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
+
+        let map = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        let part = |key: &str| match map.get(key) {
+            Some(v) if !v.is_null() => v
+                .get::<String>()
+                .cloned()
+                .map(Some)
+                .ok_or(format!("Expected answers.{key} to be null or string.")),
+            _ => Ok(None),
+        };
+
+        Ok(Answers {
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AnswerStatus, Answers};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn round_trips_answers() {
+        let answers = Answers {
+            part_1: Some("143".into()),
+            part_2: None,
+        };
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json.as_str()).unwrap(), answers);
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::try_from(r#"{ "part_1": "143" }"#).unwrap();
+        assert_eq!(answers.check(1, "143"), AnswerStatus::Correct);
+        assert_eq!(
+            answers.check(1, "144"),
+            AnswerStatus::Incorrect {
+                expected: "143".into()
+            }
+        );
+        assert_eq!(answers.check(2, "123"), AnswerStatus::Unknown);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        Answers::try_from(r#"{ "part_1": 143 }"#).unwrap();
+    }
+}
@@ -1,10 +1,12 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
+    path::Path,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{default_year, Day};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -35,6 +37,7 @@ pub fn check() -> Result<(), AocCommandError> {
 
 pub fn read(day: Day) -> Result<Output, AocCommandError> {
     let puzzle_path = get_puzzle_path(day);
+    create_parent_dir(&puzzle_path);
 
     let args = build_args(
         "read",
@@ -46,13 +49,16 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    call_aoc_cli(&args)
+    call_aoc_cli(&args, false)
 }
 
 pub fn download(day: Day) -> Result<Output, AocCommandError> {
     let input_path = get_input_path(day);
     let puzzle_path = get_puzzle_path(day);
 
+    create_parent_dir(&input_path);
+    create_parent_dir(&puzzle_path);
+
     let args = build_args(
         "download",
         &[
@@ -65,19 +71,21 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    let output = call_aoc_cli(&args)?;
+    let output = call_aoc_cli(&args, false)?;
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
     Ok(output)
 }
 
+/// Submit an answer. The output of aoc-cli is captured (and printed), so that the verdict can be read from it.
 pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    call_aoc_cli(&args, true)
 }
 
 fn get_input_path(day: Day) -> String {
@@ -88,35 +96,48 @@ fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
+/// Days of other events are placed in a folder per year, which aoc-cli does not create.
+fn create_parent_dir(path: &str) {
+    if let Some(parent) = Path::new(path).parent() {
+        let _ = fs::create_dir_all(parent);
     }
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
+    if let Some(year) = day.year().or_else(default_year) {
         cmd_args.push("--year".into());
         cmd_args.push(year.to_string());
     }
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--day".into(),
+        day.into_inner().to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+/// Call aoc-cli. With `capture_stdout`, its stdout is kept in the returned [`Output`] (and printed) instead of passed through.
+fn call_aoc_cli(args: &[String], capture_stdout: bool) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(if capture_stdout {
+            Stdio::piped()
+        } else {
+            Stdio::inherit()
+        })
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
 
+    if capture_stdout {
+        print!("{}", String::from_utf8_lossy(&output.stdout));
+    }
+
     if output.status.success() {
         Ok(output)
     } else {
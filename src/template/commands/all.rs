@@ -1,5 +1,9 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{all_days, all_days_of, run_multi::run_multi};
+
+/// Run every day of the event of `AOC_YEAR`, or of `year`.
+pub fn handle(year: Option<u16>, is_release: bool, timeout: Option<Duration>, jobs: usize) {
+    let days = year.map_or_else(all_days, all_days_of);
+    run_multi(&days.collect(), is_release, None, timeout, jobs);
 }
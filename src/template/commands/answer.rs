@@ -0,0 +1,19 @@
+use std::process;
+
+use crate::template::{answers, Day};
+
+/// Manually record the accepted answer of a part.
+pub fn handle(day: Day, part: u8, answer: &str) {
+    if !(1..=2).contains(&part) {
+        eprintln!("Unexpected part {part}, expected 1 or 2.");
+        process::exit(1);
+    }
+
+    match answers::store_answer(day, part, answer) {
+        Ok(path) => println!("Stored answer in \"{path}\"."),
+        Err(e) => {
+            eprintln!("Failed to store answer: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_cli, commands::example, Day};
 use std::process;
 
 pub fn handle(day: Day) {
@@ -11,4 +11,7 @@ pub fn handle(day: Day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
+
+    example::handle_default(day);
+    example::prefill_answers(day);
 }
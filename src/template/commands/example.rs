@@ -0,0 +1,147 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{examples, puzzle, Day};
+
+/// List the code blocks of the downloaded puzzle and write one of them to `data/examples/{day}.txt`.
+/// Without `pick`, the first block that follows "For example" is written.
+/// An example file that is not empty is only replaced with `overwrite`.
+pub fn handle(day: Day, pick: Option<usize>, overwrite: bool) {
+    let Some(markdown) = puzzle::read(day) else {
+        eprintln!(
+            "Puzzle \"{}\" not found, run `cargo download {day}` first.",
+            puzzle::get_path(day)
+        );
+        process::exit(1);
+    };
+
+    let blocks = puzzle::code_blocks(&markdown);
+
+    let Some(default) = puzzle::default_example(&blocks) else {
+        eprintln!("No code blocks found in \"{}\".", puzzle::get_path(day));
+        process::exit(1);
+    };
+
+    let index = match pick {
+        Some(0) => {
+            eprintln!("Unexpected example 0, examples are counted from 1.");
+            process::exit(1);
+        }
+        Some(n) => n - 1,
+        None => default,
+    };
+
+    for (i, block) in blocks.iter().enumerate() {
+        let marker = if i == index { "*" } else { " " };
+        let first_line = block.content.lines().next().unwrap_or_default();
+        let preview: String = first_line.chars().take(40).collect();
+        let ellipsis = if preview.len() < first_line.len() {
+            "…"
+        } else {
+            ""
+        };
+
+        println!(
+            "{marker} {:>2}: {preview}{ellipsis} ({} lines)",
+            i + 1,
+            block.content.lines().count()
+        );
+    }
+
+    let Some(block) = blocks.get(index) else {
+        eprintln!(
+            "Unexpected example {}, expected 1 to {}.",
+            index + 1,
+            blocks.len()
+        );
+        process::exit(1);
+    };
+
+    match write_example(day, &block.content, overwrite) {
+        Ok(path) => println!("Wrote example {} to \"{path}\".", index + 1),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Write the default example of a downloaded puzzle, unless the day has an example already.
+pub fn handle_default(day: Day) {
+    let Some(markdown) = puzzle::read(day) else {
+        return;
+    };
+
+    let blocks = puzzle::code_blocks(&markdown);
+
+    let Some(block) = puzzle::default_example(&blocks).map(|i| &blocks[i]) else {
+        return;
+    };
+
+    if let Ok(path) = write_example(day, &block.content, false) {
+        println!("🎄 Successfully wrote example to \"{path}\", run `cargo example {day}` to pick another one.");
+    }
+}
+
+/// Declare the example answers found in the puzzle description in the header of `data/examples/{day}.txt`,
+/// so that they are checked by [`example_tests!`](crate::example_tests).
+/// Run again after part one is solved to pick up the answer of part two. Answers declared already are kept.
+pub fn prefill_answers(day: Day) {
+    let path = get_path(day);
+
+    let (Some(markdown), Ok(mut content)) = (puzzle::read(day), fs::read_to_string(&path)) else {
+        return;
+    };
+
+    // NOTE: an example that was not written yet has no answers to declare.
+    if examples::strip_header(&content).trim().is_empty() {
+        return;
+    }
+
+    let mut prefilled = vec![];
+
+    for (part, answer) in (1..=2).zip(puzzle::example_answers(&markdown)) {
+        let Some(answer) = answer else {
+            continue;
+        };
+
+        if let Some(updated) = examples::with_answer(&content, part, &answer) {
+            content = updated;
+            prefilled.push(format!("part {part} = {answer}"));
+        }
+    }
+
+    if prefilled.is_empty() {
+        return;
+    }
+
+    match fs::write(&path, content) {
+        Ok(()) => println!(
+            "🎄 Prefilled example answers in \"{path}\": {}.",
+            prefilled.join(", ")
+        ),
+        Err(e) => eprintln!("Failed to prefill example answers: {e}"),
+    }
+}
+
+fn get_path(day: Day) -> String {
+    format!("data/examples/{day}.txt")
+}
+
+fn write_example(day: Day, content: &str, overwrite: bool) -> Result<String, String> {
+    let path = get_path(day);
+
+    let is_empty = fs::read_to_string(&path).map_or(true, |c| c.trim().is_empty());
+
+    if !is_empty && !overwrite {
+        return Err(format!(
+            "Example \"{path}\" is not empty, pass --overwrite to replace it."
+        ));
+    }
+
+    if let Some(parent) = Path::new(&path).parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(&path, content).map_err(|e| format!("Failed to write example: {e}"))?;
+
+    Ok(path)
+}
@@ -1,10 +1,15 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{
+    commands::example,
+    registry::{self, REGISTRY_PATH},
+    Day,
+};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
@@ -20,6 +25,11 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
+    // NOTE: days of other events are placed in a folder per year.
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +37,28 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
+/// Add the day to the registry bin. Returns `false` if it was registered already.
+fn register_day(day: Day) -> Result<bool, std::io::Error> {
+    let content = fs::read_to_string(REGISTRY_PATH)?;
+
+    match registry::add_entry(&content, day) {
+        Some(content) => fs::write(REGISTRY_PATH, content).map(|()| true),
+        None => Ok(false),
+    }
+}
+
+/// The day as passed to [`solution!`](crate::solution), e.g. `5` or `2023/5`.
+fn day_number(day: Day) -> String {
+    match day.year() {
+        Some(year) => format!("{year}/{}", day.into_inner()),
+        None => day.into_inner().to_string(),
+    }
+}
+
 pub fn handle(day: Day, overwrite: bool) {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    let module_path = format!("src/bin/{}.rs", day.bin_name());
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -42,7 +70,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match file.write_all(
         MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .replace("%DAY_NUMBER%", &day_number(day))
             .as_bytes(),
     ) {
         Ok(()) => {
@@ -54,6 +82,16 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    match register_day(day) {
+        Ok(true) => {
+            println!("Registered module in \"{REGISTRY_PATH}\"");
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to register module: {e}");
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -74,6 +112,8 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    example::prefill_answers(day);
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
@@ -1,9 +1,53 @@
 use std::process::{Command, Stdio};
+use std::time::Duration;
 
 use crate::template::Day;
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Options of `--submit`, forwarded to the solution.
+pub struct Submit {
+    pub part: u8,
+    /// Submit even if the submission ledger refuses the answer.
+    pub force: bool,
+    /// Wait for the cooldown to expire instead of giving up.
+    pub wait: bool,
+}
+
+/// Options of `--viz`, forwarded to the solution.
+pub struct Viz {
+    /// `terminal`, `ppm`, `png` or `gif`.
+    pub format: Option<String>,
+    pub fps: Option<u32>,
+}
+
+/// Options that are forwarded to the solution.
+pub struct Options {
+    pub submit: Option<Submit>,
+    pub viz: Option<Viz>,
+    /// Verbosity of the debug output, 1 for `-v` and 2 for `-vv`.
+    pub verbosity: u8,
+    /// Write the debug output to `target/log/{day}.log` instead of stderr.
+    pub log: bool,
+    pub params: Vec<String>,
+    pub timeout: Option<Duration>,
+    /// Run against this file, or stdin for `-`, instead of the puzzle input.
+    pub input: Option<String>,
+    /// Run against an example instead of the puzzle input, `None` for the first one.
+    pub example: Option<Option<usize>>,
+}
+
+pub fn handle(day: Day, release: bool, dhat: bool, options: Options) {
+    let Options {
+        submit,
+        viz,
+        verbosity,
+        log,
+        params,
+        timeout,
+        input,
+        example,
+    } = options;
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -16,11 +60,65 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push("--release".to_string());
     }
 
+    if viz.is_some() {
+        cmd_args.extend(["--features".to_string(), "viz".to_string()]);
+    }
+
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit) = submit {
         cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+        cmd_args.push(submit.part.to_string());
+
+        if submit.force {
+            cmd_args.push("--force".to_string());
+        }
+
+        if submit.wait {
+            cmd_args.push("--wait".to_string());
+        }
+    }
+
+    if let Some(viz) = viz {
+        cmd_args.push("--viz".to_string());
+
+        if let Some(format) = viz.format {
+            cmd_args.push("--viz-format".to_string());
+            cmd_args.push(format);
+        }
+
+        if let Some(fps) = viz.fps {
+            cmd_args.push("--viz-fps".to_string());
+            cmd_args.push(fps.to_string());
+        }
+    }
+
+    if verbosity > 0 {
+        cmd_args.push(format!("-{}", "v".repeat(verbosity.into())));
+
+        if log {
+            cmd_args.push("--log".to_string());
+        }
+    }
+
+    for param in params {
+        cmd_args.push("--param".to_string());
+        cmd_args.push(param);
+    }
+
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_secs().to_string());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input);
+    }
+
+    if let Some(example) = example {
+        cmd_args.push("--example".to_string());
+        cmd_args.extend(example.map(|n| n.to_string()));
     }
 
     let mut cmd = Command::new("cargo")
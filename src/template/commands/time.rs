@@ -1,32 +1,71 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
+use crate::template::history::{self, HistoryEntry};
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::runner::BenchConfig;
+use crate::template::timings::{TimingDelta, Timings};
+use crate::template::{all_days, all_days_of, readme_benchmarks, Day, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+/// Default threshold (in percent) above which `--compare` reports a slowdown as a regression.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+/// Bench a set of days of the event of `AOC_YEAR`, or of `year`. If `compare` is set, the results are compared against the stored timings
+/// and the process exits with a non-zero status if a part regressed by more than `compare` percent.
+pub fn handle(
+    day: Option<Day>,
+    year: Option<u16>,
+    run_all: bool,
+    store: bool,
+    bench: BenchConfig,
+    timeout: Option<Duration>,
+    compare: Option<f64>,
+) {
     let stored_timings = Timings::read_from_file();
+    let days = || year.map_or_else(all_days, all_days_of);
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                days().collect()
+            } else if compare.is_some() {
+                // when comparing, re-run every day that has stored timings.
+                stored_timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                days()
+                    .filter(|day| !stored_timings.is_day_complete(day))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    // NOTE: benchmarks always run serially.
+    let timings = run_multi(&days_to_run, true, Some(bench), timeout, 1).unwrap();
+
+    let has_regressions = compare.is_some_and(|threshold| {
+        print_comparison(&timings.compare(&stored_timings, &days_to_run), threshold)
+    });
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
+        // NOTE: days that failed or timed out keep their stored timings.
+        let completed = Timings {
+            data: timings
+                .data
+                .iter()
+                .filter(|t| !t.failed && !t.timed_out)
+                .cloned()
+                .collect(),
+        };
+        let merged_timings = stored_timings.merge(&completed);
         merged_timings.store_file().unwrap();
 
+        if let Err(e) = history::append(&HistoryEntry::capture(timings)) {
+            eprintln!("Failed to append to timing history: {e}");
+        }
+
         println!();
         match readme_benchmarks::update(merged_timings) {
             Ok(()) => {
@@ -37,4 +76,114 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// Print the deltas against the stored timings. Returns whether any part regressed beyond `threshold` percent.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn print_comparison(deltas: &[TimingDelta], threshold: f64) -> bool {
+    let to_duration = |nanos: f64| Duration::from_nanos(nanos as u64);
+
+    println!("\n{ANSI_BOLD}Comparison (threshold: {threshold:.1}%){ANSI_RESET}");
+    println!("------");
+
+    if deltas.is_empty() {
+        println!("No stored timings to compare against.");
+        return false;
+    }
+
+    let mut regressions = 0;
+
+    for delta in deltas {
+        let part = match delta.part {
+            0 => "Parse".to_string(),
+            part => format!("Part {part}"),
+        };
+
+        let marker = if delta.is_regression(threshold) {
+            regressions += 1;
+            " ✖ regression"
+        } else {
+            ""
+        };
+
+        let current = match (delta.current_nanos, delta.change_percent()) {
+            (Some(nanos), Some(change)) => format!("{:.1?} ({change:+.1}%)", to_duration(nanos)),
+            _ => "missing".to_string(),
+        };
+
+        println!(
+            "Day {} {part}: {:.1?} → {current}{marker}",
+            delta.day,
+            to_duration(delta.baseline_nanos),
+        );
+    }
+
+    if regressions > 0 {
+        println!("\n{ANSI_BOLD}{regressions} regression(s) above {threshold:.1}%.{ANSI_RESET}");
+    }
+
+    regressions > 0
+}
+
+/// Print how the timings of a day evolved across all stored runs.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn handle_history(day: Day) {
+    let entries = match history::read_from_file() {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to read timing history: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("{ANSI_BOLD}Day {day} history{ANSI_RESET}");
+    println!("------");
+
+    let mut previous_nanos: Option<f64> = None;
+    let mut has_entries = false;
+
+    for entry in &entries {
+        let Some(timing) = entry.timings.data.iter().find(|t| t.day == day) else {
+            continue;
+        };
+        has_entries = true;
+
+        let part = |part: u8| {
+            timing.part_nanos(part).map_or("-".into(), |nanos| {
+                format!("{:.1?}", Duration::from_nanos(nanos as u64))
+            })
+        };
+
+        let change =
+            previous_nanos
+                .filter(|previous| *previous > 0.0)
+                .map_or(String::new(), |previous| {
+                    format!(
+                        " ({:+.1}%)",
+                        (timing.total_nanos - previous) / previous * 100.0
+                    )
+                });
+
+        println!(
+            "{} | {} | rustc {} | {} | parse {} | part 1 {} | part 2 {} | total {:.1?}{change}",
+            history::format_timestamp(entry.timestamp),
+            entry.commit.as_deref().unwrap_or("-"),
+            entry.rustc.as_deref().unwrap_or("-"),
+            entry.host.as_deref().unwrap_or("-"),
+            part(0),
+            part(1),
+            part(2),
+            Duration::from_nanos(timing.total_nanos as u64),
+        );
+
+        previous_nanos = Some(timing.total_nanos);
+    }
+
+    if !has_entries {
+        println!("No stored timings. Run `cargo time {day} --store` to record some.");
+    }
 }
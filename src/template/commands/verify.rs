@@ -0,0 +1,316 @@
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+use crate::template::answers::{AnswerStatus, Answers};
+use crate::template::run_multi::child_commands::{self, OutputLine, OutputMode, SolutionOutput};
+use crate::template::run_multi::{get_path_for_bin, run_parallel};
+use crate::template::{all_days, all_days_of, Day, ANSI_BOLD, ANSI_RESET};
+
+/// Outcome of verifying a single part against its known answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The part returned an answer, but no answer is known for it.
+    MissingAnswer,
+    /// The part did not return an answer.
+    NoAnswer,
+    /// The part or the parse stage returned an error.
+    Error {
+        message: String,
+    },
+    /// The part did not run because its bin panicked.
+    Panic,
+    TimedOut,
+    /// The part is not implemented and no answer is known for it, e.g. part 2 of day 25.
+    Skipped,
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Verdict::Pass | Verdict::Skipped)
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "✔",
+            Verdict::Mismatch { .. } => "✖ mismatch",
+            Verdict::MissingAnswer => "? missing",
+            Verdict::NoAnswer => "✖ no answer",
+            Verdict::Error { .. } => "✖ error",
+            Verdict::Panic => "✖ panic",
+            Verdict::TimedOut => "✖ timeout",
+            Verdict::Skipped => "-",
+        }
+    }
+}
+
+/// Verify a part of a day's run against the known answers.
+pub fn verify_part(output: &SolutionOutput, answers: &Answers, part: u8) -> Verdict {
+    let Some(report) = output.reports.iter().find(|r| r.part == part) else {
+        return if output.timed_out {
+            Verdict::TimedOut
+        } else if !output.success {
+            Verdict::Panic
+        } else if answers.get(part).is_some() {
+            Verdict::NoAnswer
+        } else {
+            Verdict::Skipped
+        };
+    };
+
+    if report.timed_out {
+        return Verdict::TimedOut;
+    }
+
+    let Some(answer) = &report.answer else {
+        let parse_error = output
+            .reports
+            .iter()
+            .find(|r| r.part == 0)
+            .and_then(|r| r.error.as_ref());
+
+        return match report.error.as_ref().or(parse_error) {
+            Some(message) => Verdict::Error {
+                message: message.clone(),
+            },
+            None => Verdict::NoAnswer,
+        };
+    };
+
+    match answers.check(part, answer) {
+        AnswerStatus::Correct => Verdict::Pass,
+        AnswerStatus::Incorrect { expected } => Verdict::Mismatch {
+            expected,
+            actual: answer.clone(),
+        },
+        AnswerStatus::Unknown => Verdict::MissingAnswer,
+    }
+}
+
+/// Run every scaffolded day of the event of `AOC_YEAR`, or of `year`, against its input and compare both parts to the known answers.
+/// Exits with a non-zero status if any part fails.
+pub fn handle(year: Option<u16>, timeout: Option<Duration>, jobs: usize) {
+    let days: Vec<Day> = year
+        .map_or_else(all_days, all_days_of)
+        .filter(|day| Path::new(&get_path_for_bin(*day)).exists())
+        .collect();
+
+    if days.is_empty() {
+        println!("No days have been scaffolded yet.");
+        return;
+    }
+
+    let registry = child_commands::build_registry(true).unwrap();
+
+    println!(
+        "{ANSI_BOLD}{:<6} {:<14} {:<14}{ANSI_RESET}",
+        "Day", "Part 1", "Part 2"
+    );
+    println!("------");
+
+    let mut failures: Vec<(Day, u8, Verdict, Vec<String>)> = vec![];
+
+    run_parallel(
+        &days,
+        jobs,
+        |day| {
+            child_commands::run_solution(
+                day,
+                None,
+                true,
+                timeout,
+                registry.as_deref(),
+                OutputMode::Buffer,
+            )
+            .unwrap()
+        },
+        |day, output| {
+            let answers = Answers::read_from_file(day);
+            let verdicts = [1, 2].map(|part| verify_part(&output, &answers, part));
+
+            println!(
+                "{:<6} {:<14} {:<14}",
+                day.to_string(),
+                verdicts[0].label(),
+                verdicts[1].label()
+            );
+
+            let stderr: Vec<String> = output
+                .output
+                .iter()
+                .filter_map(|line| match line {
+                    OutputLine::Stderr(line) => Some(line.clone()),
+                    OutputLine::Stdout(_) => None,
+                })
+                .collect();
+
+            for (part, verdict) in (1..=2).zip(verdicts) {
+                if verdict.is_failure() {
+                    failures.push((day, part, verdict, stderr.clone()));
+                }
+            }
+        },
+    );
+
+    if failures.is_empty() {
+        println!("\n{ANSI_BOLD}All {} days verified.{ANSI_RESET}", days.len());
+        return;
+    }
+
+    println!("\n{ANSI_BOLD}Failures:{ANSI_RESET}");
+
+    // NOTE: a panic fails every remaining part of a day, only print its output once.
+    let mut last_panic: Option<Day> = None;
+
+    for (day, part, verdict, stderr) in &failures {
+        match verdict {
+            Verdict::Mismatch { expected, actual } => {
+                println!("Day {day} part {part}: expected {expected}, got {actual}");
+            }
+            Verdict::Error { message } => {
+                println!("Day {day} part {part}: {message}");
+            }
+            Verdict::MissingAnswer => {
+                println!("Day {day} part {part}: no known answer, record one with `cargo answer {day} {part} <answer>`");
+            }
+            Verdict::Panic => {
+                println!("Day {day} part {part}: panicked");
+                if last_panic.replace(*day) != Some(*day) {
+                    for line in stderr.iter().filter(|l| !l.trim().is_empty()) {
+                        println!("    {line}");
+                    }
+                }
+            }
+            _ => println!("Day {day} part {part}: {}", verdict.label()),
+        }
+    }
+
+    process::exit(1);
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{verify_part, Verdict};
+    use crate::template::{
+        answers::Answers, report::PartReport, run_multi::child_commands::SolutionOutput,
+        runner::BenchStats,
+    };
+    use std::time::Duration;
+
+    fn report(part: u8, answer: Option<&str>) -> PartReport {
+        PartReport {
+            part,
+            success: answer.is_some(),
+            timed_out: false,
+            answer: answer.map(str::to_string),
+            error: None,
+            stats: BenchStats::single(Duration::from_nanos(1)),
+            heap: None,
+            counters: None,
+        }
+    }
+
+    fn answers(part_1: Option<&str>, part_2: Option<&str>) -> Answers {
+        Answers {
+            part_1: part_1.map(str::to_string),
+            part_2: part_2.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn verifies_parts() {
+        let output = SolutionOutput {
+            reports: vec![report(1, Some("143")), report(2, Some("124"))],
+            success: true,
+            ..SolutionOutput::default()
+        };
+        let answers = answers(Some("143"), Some("123"));
+
+        assert_eq!(verify_part(&output, &answers, 1), Verdict::Pass);
+        assert_eq!(
+            verify_part(&output, &answers, 2),
+            Verdict::Mismatch {
+                expected: "123".into(),
+                actual: "124".into()
+            }
+        );
+    }
+
+    #[test]
+    fn fails_for_missing_answers() {
+        let output = SolutionOutput {
+            reports: vec![report(1, Some("143")), report(2, None)],
+            success: true,
+            ..SolutionOutput::default()
+        };
+
+        assert_eq!(
+            verify_part(&output, &answers(None, None), 1),
+            Verdict::MissingAnswer
+        );
+        assert_eq!(
+            verify_part(&output, &answers(None, Some("123")), 2),
+            Verdict::NoAnswer
+        );
+    }
+
+    #[test]
+    fn fails_for_panics_and_timeouts() {
+        let panicked = SolutionOutput {
+            reports: vec![report(1, Some("143"))],
+            success: false,
+            ..SolutionOutput::default()
+        };
+        assert_eq!(
+            verify_part(&panicked, &answers(Some("143"), None), 2),
+            Verdict::Panic
+        );
+
+        let timed_out = SolutionOutput {
+            timed_out: true,
+            ..SolutionOutput::default()
+        };
+        assert_eq!(
+            verify_part(&timed_out, &answers(None, None), 1),
+            Verdict::TimedOut
+        );
+    }
+
+    #[test]
+    fn skips_unimplemented_parts() {
+        let output = SolutionOutput {
+            reports: vec![report(1, Some("143"))],
+            success: true,
+            ..SolutionOutput::default()
+        };
+        let verdict = verify_part(&output, &answers(Some("143"), None), 2);
+        assert_eq!(verdict, Verdict::Skipped);
+        assert_eq!(verdict.is_failure(), false);
+    }
+
+    #[test]
+    fn fails_for_errors() {
+        let mut parse = report(0, None);
+        parse.error = Some("invalid digit found in string".into());
+
+        let output = SolutionOutput {
+            reports: vec![parse, report(1, None), report(2, None)],
+            success: true,
+            ..SolutionOutput::default()
+        };
+
+        assert_eq!(
+            verify_part(&output, &answers(Some("143"), None), 1),
+            Verdict::Error {
+                message: "invalid digit found in string".into()
+            }
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::env;
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
@@ -8,48 +9,163 @@ use chrono::{Datelike, FixedOffset, Utc};
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A valid day number of advent (i.e. an integer in range 1 to the last day of the event), optionally of an event other than `AOC_YEAR`.
+/// Events until 2024 have 25 days, later events have 12, see [`days_in_event`].
 ///
 /// # Display
-/// This value displays as a two digit number.
+/// This value displays as a two digit number, prefixed by the year for other events.
+/// Paths like `data/inputs/{day}.txt` thereby place other events in a folder per year.
 ///
 /// ```
-/// # use advent_of_code::Day;
+/// # use advent_of_code::template::Day;
 /// let day = Day::new(8).unwrap();
-/// assert_eq!(day.to_string(), "08")
+/// assert_eq!(day.to_string(), "08");
+/// assert_eq!(day.with_year(2023).to_string(), "2023/08");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Day(u8);
+pub struct Day {
+    /// The year of the event, `None` for the event of `AOC_YEAR`.
+    year: Option<u16>,
+    day: u8,
+}
 
 impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > days_in_event(None) {
             return None;
         }
-        Some(Self(day))
+        Some(Self { year: None, day })
+    }
+
+    /// The same day of another event. The event of `AOC_YEAR` is kept without a year.
+    #[must_use]
+    pub fn with_year(self, year: u16) -> Self {
+        Self {
+            year: (default_year() != Some(year)).then_some(year),
+            day: self.day,
+        }
     }
 
     // Not part of the public API
     #[doc(hidden)]
     pub const fn __new_unchecked(day: u8) -> Self {
-        Self(day)
+        Self { year: None, day }
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked_with_year(year: Option<u16>, day: u8) -> Self {
+        Self { year, day }
     }
 
     /// Converts the [`Day`] into an [`u8`].
     pub fn into_inner(self) -> u8 {
-        self.0
+        self.day
+    }
+
+    /// The year of the event, `None` for the event of `AOC_YEAR`.
+    pub fn year(self) -> Option<u16> {
+        self.year
+    }
+
+    /// Whether this is the last day of its event, which only has a single part.
+    pub fn is_last(self) -> bool {
+        self.day == days_in_event(self.year)
+    }
+
+    /// The name of the solution bin, e.g. `05` or `2023-05`.
+    /// Cargo only discovers bins directly inside `src/bin`, so other events are not placed in a folder.
+    pub fn bin_name(self) -> String {
+        self.to_string().replace('/', "-")
+    }
+}
+
+/// The year of the event this repository is set up for, read from `AOC_YEAR`.
+pub fn default_year() -> Option<u16> {
+    env::var("AOC_YEAR").ok()?.parse().ok()
+}
+
+/// The number of days of an event, `None` being the event of `AOC_YEAR`.
+/// `AOC_DAYS` overrides the number of days of the event of `AOC_YEAR`.
+pub fn days_in_event(year: Option<u16>) -> u8 {
+    __days_in_event(
+        year,
+        env::var("AOC_YEAR").ok().as_deref(),
+        env::var("AOC_DAYS").ok().as_deref(),
+    )
+}
+
+// Not part of the public API, the year of a day as stored by `day!`. Like [`Day::with_year`],
+// the event of `AOC_YEAR` is stored as `None`.
+#[doc(hidden)]
+pub const fn __event_year(year: u16, aoc_year: Option<&str>) -> Option<u16> {
+    match parse_const(aoc_year) {
+        Some(aoc_year) if aoc_year == year => None,
+        _ => Some(year),
     }
 }
 
+/// The first event with 12 days instead of 25.
+const FIRST_SHORT_EVENT: u16 = 2025;
+
+// Not part of the public API, the configuration is passed in so that `day!` can check days at compile time.
+#[doc(hidden)]
+pub const fn __days_in_event(
+    year: Option<u16>,
+    aoc_year: Option<&str>,
+    aoc_days: Option<&str>,
+) -> u8 {
+    let year = match year {
+        Some(year) => Some(year),
+        None => {
+            if let Some(days) = parse_const(aoc_days) {
+                if days >= 1 && days <= 25 {
+                    return days as u8;
+                }
+            }
+            parse_const(aoc_year)
+        }
+    };
+
+    match year {
+        Some(year) if year >= FIRST_SHORT_EVENT => 12,
+        _ => 25,
+    }
+}
+
+/// Parse a number in a const context.
+const fn parse_const(s: Option<&str>) -> Option<u16> {
+    let Some(s) = s else {
+        return None;
+    };
+
+    let bytes = s.as_bytes();
+    if bytes.is_empty() || bytes.len() > 4 {
+        return None;
+    }
+
+    let mut value = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    Some(value)
+}
+
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's between the 1st of december and the last day of the event, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        if today.month() == 12 && today.day() <= u32::from(days_in_event(None)) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -59,19 +175,22 @@ impl Day {
 
 impl Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:02}", self.0)
+        match self.year {
+            Some(year) => write!(f, "{year}/{:02}", self.day),
+            None => write!(f, "{:02}", self.day),
+        }
     }
 }
 
 impl PartialEq<u8> for Day {
     fn eq(&self, other: &u8) -> bool {
-        self.0.eq(other)
+        self.day.eq(other)
     }
 }
 
 impl PartialOrd<u8> for Day {
     fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(other)
+        self.day.partial_cmp(other)
     }
 }
 
@@ -80,9 +199,23 @@ impl PartialOrd<u8> for Day {
 impl FromStr for Day {
     type Err = DayFromStrError;
 
+    /// Parses `05`, or `2023/05` for a day of another event.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let (year, day) = match s.split_once('/') {
+            Some((year, day)) => (Some(year.parse().map_err(|_| DayFromStrError)?), day),
+            None => (None, s),
+        };
+
+        let day: u8 = day.parse().map_err(|_| DayFromStrError)?;
+        let day = match year {
+            Some(year) => Self::__new_unchecked(day).with_year(year),
+            None => Self::__new_unchecked(day),
+        };
+
+        if day.day == 0 || day.day > days_in_event(day.year) {
+            return Err(DayFromStrError);
+        }
+        Ok(day)
     }
 }
 
@@ -94,26 +227,45 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        f.write_str(
+            "expecting a day number of the event, optionally prefixed by a year like `2023/05`",
+        )
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to the last day of the event.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of another event, see [`Day::with_year`].
+pub fn all_days_of(year: u16) -> AllDays {
+    let year = Day::__new_unchecked(1).with_year(year).year;
+
+    AllDays {
+        year,
+        current: 1,
+        last: days_in_event(year),
+    }
+}
+
+/// An iterator that yields every day of advent from the 1st to the last day of the event.
 pub struct AllDays {
+    year: Option<u16>,
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self {
+            year: None,
+            current: 1,
+            last: days_in_event(None),
+        }
     }
 }
 
@@ -121,11 +273,14 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
-        let day = Day(self.current);
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
+        let day = Day {
+            year: self.year,
+            day: self.current,
+        };
         self.current += 1;
 
         Some(day)
@@ -134,16 +289,42 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
-/// Creates a [`Day`] value in a const context.
+/// Creates a [`Day`] value in a const context. A day of another event is created with `day!(2023 / 5)`.
 #[macro_export]
 macro_rules! day {
+    ($year:literal / $day:literal) => {{
+        const YEAR: Option<u16> = $crate::template::__event_year($year, option_env!("AOC_YEAR"));
+        const _ASSERT: () = assert!(
+            $day != 0
+                && $day
+                    <= $crate::template::__days_in_event(
+                        YEAR,
+                        option_env!("AOC_YEAR"),
+                        option_env!("AOC_DAYS"),
+                    ),
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, expecting a day of the ",
+                $year,
+                " event"
+            ),
+        );
+        $crate::template::Day::__new_unchecked_with_year(YEAR, $day)
+    }};
     ($day:expr) => {{
         const _ASSERT: () = assert!(
-            $day != 0 && $day <= 25,
+            $day != 0
+                && $day
+                    <= $crate::template::__days_in_event(
+                        None,
+                        option_env!("AOC_YEAR"),
+                        option_env!("AOC_DAYS"),
+                    ),
             concat!(
                 "invalid day number `",
                 $day,
-                "`, expecting a value between 1 and 25"
+                "`, expecting a day of the event, see `AOC_YEAR` and `AOC_DAYS`"
             ),
         );
         $crate::template::Day::__new_unchecked($day)
@@ -154,37 +335,84 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{__days_in_event, __event_year, all_days, all_days_of, default_year, Day};
+
+    #[test]
+    fn counts_days_per_event() {
+        assert_eq!(__days_in_event(None, Some("2024"), None), 25);
+        assert_eq!(__days_in_event(None, Some("2025"), None), 12);
+        assert_eq!(__days_in_event(None, Some("2024"), Some("12")), 12);
+        assert_eq!(__days_in_event(None, None, Some("invalid")), 25);
+        assert_eq!(__days_in_event(Some(2025), Some("2024"), Some("20")), 12);
+        assert_eq!(__days_in_event(Some(2015), Some("2025"), None), 25);
+
+        assert!("2025/13".parse::<Day>().is_err());
+        assert_eq!(all_days_of(2025).count(), 12);
+        assert!(crate::day!(2025 / 12).is_last());
+        assert!(!crate::day!(2023 / 12).is_last());
+    }
+
+    #[test]
+    fn parses_days_of_other_events() {
+        let day: Day = "2023/05".parse().unwrap();
+        assert_eq!(day, crate::day!(2023 / 5));
+        assert_eq!(day.year(), Some(2023));
+        assert_eq!(day.to_string(), "2023/05");
+        assert_eq!(day.bin_name(), "2023-05");
+
+        let day: Day = "05".parse().unwrap();
+        assert_eq!(day.year(), None);
+        assert_eq!(day.bin_name(), "05");
+
+        assert!("2023/26".parse::<Day>().is_err());
+        assert!("twenty/05".parse::<Day>().is_err());
+        assert_eq!(all_days_of(2023).last(), Some(crate::day!(2023 / 25)));
+    }
+
+    #[test]
+    fn stores_the_default_year_as_none() {
+        assert_eq!(__event_year(2024, Some("2024")), None);
+        assert_eq!(__event_year(2023, Some("2024")), Some(2023));
+        assert_eq!(__event_year(2024, None), Some(2024));
+
+        assert_eq!(crate::day!(2023 / 5), "2023/05".parse().unwrap());
+        assert_eq!(crate::day!(2024 / 5), "2024/05".parse().unwrap());
+        assert_eq!(crate::day!(2025 / 5), "2025/05".parse().unwrap());
+
+        if default_year() == Some(2024) {
+            assert_eq!(crate::day!(2024 / 5), crate::day!(5));
+        }
+    }
 
     #[test]
     fn all_days_iterator() {
         let mut iter = all_days();
 
-        assert_eq!(iter.next(), Some(Day(1)));
-        assert_eq!(iter.next(), Some(Day(2)));
-        assert_eq!(iter.next(), Some(Day(3)));
-        assert_eq!(iter.next(), Some(Day(4)));
-        assert_eq!(iter.next(), Some(Day(5)));
-        assert_eq!(iter.next(), Some(Day(6)));
-        assert_eq!(iter.next(), Some(Day(7)));
-        assert_eq!(iter.next(), Some(Day(8)));
-        assert_eq!(iter.next(), Some(Day(9)));
-        assert_eq!(iter.next(), Some(Day(10)));
-        assert_eq!(iter.next(), Some(Day(11)));
-        assert_eq!(iter.next(), Some(Day(12)));
-        assert_eq!(iter.next(), Some(Day(13)));
-        assert_eq!(iter.next(), Some(Day(14)));
-        assert_eq!(iter.next(), Some(Day(15)));
-        assert_eq!(iter.next(), Some(Day(16)));
-        assert_eq!(iter.next(), Some(Day(17)));
-        assert_eq!(iter.next(), Some(Day(18)));
-        assert_eq!(iter.next(), Some(Day(19)));
-        assert_eq!(iter.next(), Some(Day(20)));
-        assert_eq!(iter.next(), Some(Day(21)));
-        assert_eq!(iter.next(), Some(Day(22)));
-        assert_eq!(iter.next(), Some(Day(23)));
-        assert_eq!(iter.next(), Some(Day(24)));
-        assert_eq!(iter.next(), Some(Day(25)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(1)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(2)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(3)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(4)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(5)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(6)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(7)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(8)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(9)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(10)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(11)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(12)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(13)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(14)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(15)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(16)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(17)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(18)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(19)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(20)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(21)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(22)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(23)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(24)));
+        assert_eq!(iter.next(), Some(Day::__new_unchecked(25)));
         assert_eq!(iter.next(), None);
     }
 }
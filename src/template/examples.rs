@@ -0,0 +1,296 @@
+/// Module that reads the examples of a day and checks the solution against their expected answers.
+///
+/// Every file in `data/examples` named `{day}.txt` or `{day}-{name}.txt` is an example of that day.
+/// An example may start with a header of `key: value` lines that is closed by a `---` line:
+///
+/// ```text
+/// part_1: 140
+/// part_2: 80
+/// width: 11
+/// ---
+/// AAAA
+/// ```
+///
+/// The keys `part_1` and `part_2` hold the expected answers, every other key is a parameter (see [`template::params`](crate::template::params)).
+use std::{env, fs};
+
+use crate::template::{ocr, params, Day};
+
+/// A single example of a day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    /// The file name without extension, e.g. `12-small`.
+    pub name: String,
+    /// The content after the header.
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub params: Vec<(String, String)>,
+}
+
+impl Example {
+    pub fn parse(name: &str, content: &str) -> Self {
+        let mut example = Example {
+            name: name.to_string(),
+            ..Example::default()
+        };
+
+        let Some((header, input)) = split_header(content) else {
+            example.input = content.to_string();
+            return example;
+        };
+
+        example.input = input.to_string();
+
+        for (key, value) in header {
+            match key {
+                "part_1" => example.part_1 = Some(value.to_string()),
+                "part_2" => example.part_2 = Some(value.to_string()),
+                _ => example.params.push((key.to_string(), value.to_string())),
+            }
+        }
+
+        example
+    }
+
+    /// The expected answer of a part, if the example declares one.
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Remove the header of an example, if present.
+pub fn strip_header(content: &str) -> &str {
+    split_header(content).map_or(content, |(_, input)| input)
+}
+
+/// Declare the expected answer of a part in the header of an example, adding a header if there is none.
+/// Returns `None` if the example declares an answer for the part already.
+pub fn with_answer(content: &str, part: u8, answer: &str) -> Option<String> {
+    let key = format!("part_{part}");
+    let entry = format!("{key}: {answer}\n");
+
+    let Some((header, input)) = split_header(content) else {
+        return Some(format!("{entry}---\n{content}"));
+    };
+
+    if header.iter().any(|(k, _)| *k == key) {
+        return None;
+    }
+
+    // NOTE: the entry is added last, right before the `---` line.
+    let separator = content.len() - input.len();
+    let separator = content[..separator].trim_end_matches(['\r', '\n']).len() - "---".len();
+
+    Some(format!(
+        "{}{entry}{}",
+        &content[..separator],
+        &content[separator..]
+    ))
+}
+
+/// Split an example into its header entries and its input.
+/// Returns `None` if the content does not start with a header.
+fn split_header(content: &str) -> Option<(Vec<(&str, &str)>, &str)> {
+    let mut entries = vec![];
+    let mut offset = 0;
+
+    for line in content.split_inclusive('\n') {
+        offset += line.len();
+        let line = line.trim_end_matches(['\r', '\n']);
+
+        if line == "---" {
+            return Some((entries, &content[offset..]));
+        }
+
+        let (key, value) = line.split_once(':')?;
+        let key = key.trim();
+
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return None;
+        }
+
+        entries.push((key, value.trim()));
+    }
+
+    None
+}
+
+/// Read all examples of a day, sorted by name.
+pub fn read_examples(day: Day) -> Vec<Example> {
+    // NOTE: examples of other events are placed in a folder per year, e.g. `data/examples/2023/05.txt`.
+    let path = env::current_dir()
+        .unwrap()
+        .join("data")
+        .join("examples")
+        .join(day.to_string());
+
+    let (Some(dir), Some(stem)) = (path.parent(), path.file_name().and_then(|x| x.to_str())) else {
+        return vec![];
+    };
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let prefix = format!("{stem}-");
+
+    let mut examples: Vec<Example> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            let name = path.file_stem()?.to_str()?.to_string();
+
+            let is_example = path.extension().is_some_and(|ext| ext == "txt")
+                && (name == stem || name.starts_with(&prefix));
+
+            if !is_example {
+                return None;
+            }
+
+            let content = fs::read_to_string(&path).ok()?;
+            Some(Example::parse(&name, &content))
+        })
+        .collect();
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    examples
+}
+
+/// Run a part against every example of a day that declares an expected answer for it.
+/// `solve` returns the answer, or the error of a failed part, see [`PartResult`](crate::template::runner::PartResult).
+/// Answers drawn as letters are decoded with [`ocr::decode`] before they are compared.
+/// Panics with a list of all examples that failed.
+///
+/// If no example declares an answer for the part, nothing is checked and a note is printed instead,
+/// so that a freshly scaffolded day does not fail its tests.
+pub fn check_examples(
+    day: Day,
+    part: u8,
+    solve: impl Fn(&Example) -> Result<String, Option<String>>,
+) {
+    let examples = read_examples(day);
+
+    if !examples
+        .iter()
+        .any(|example| example.expected(part).is_some())
+    {
+        eprintln!(
+            "Skipped part {part} of day {day}: no example declares an answer, add `part_{part}: <answer>` to the header of an example."
+        );
+        return;
+    }
+
+    let failures: Vec<String> = examples
+        .iter()
+        .filter_map(|example| {
+            let expected = example.expected(part)?;
+            let (result, unused) =
+                params::with_params(&example.params, || (solve(example), params::unused()));
+
+            // NOTE: only a warning, a parameter may be read by the other part only.
+            for key in unused {
+                eprintln!(
+                    "Warning: parameter `{key}` of example \"{}\" was not read by part {part}.",
+                    example.name
+                );
+            }
+
+            let actual = match result {
+                Ok(answer) => {
+                    let answer = ocr::decode(&answer).unwrap_or(answer);
+                    if answer.trim() == expected {
+                        return None;
+                    }
+                    answer
+                }
+                Err(Some(error)) => format!("error: {error}"),
+                Err(None) => "None".to_string(),
+            };
+
+            Some(format!(
+                "  {}: expected {expected}, got {actual}",
+                example.name
+            ))
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} example(s) failed for part {part}:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{strip_header, with_answer, Example};
+
+    #[test]
+    fn parses_headers() {
+        let example = Example::parse(
+            "14",
+            "part_1: 12\nwidth: 11\nheight : 7\n---\np=0,4 v=3,-3\n",
+        );
+
+        assert_eq!(example.input, "p=0,4 v=3,-3\n");
+        assert_eq!(example.expected(1), Some("12"));
+        assert_eq!(example.expected(2), None);
+        assert_eq!(example.param("width"), Some("11"));
+        assert_eq!(example.param("height"), Some("7"));
+        assert_eq!(example.param("depth"), None);
+    }
+
+    #[test]
+    fn keeps_examples_without_header() {
+        let content = "47|53\n97|13\n\n75,47,61,53,29\n";
+        let example = Example::parse("05", content);
+        assert_eq!(example.input, content);
+        assert_eq!(example.expected(1), None);
+        assert!(example.params.is_empty());
+
+        // a separator that does not follow a header is part of the input.
+        assert_eq!(strip_header("#.#\n---\n#.#"), "#.#\n---\n#.#");
+    }
+
+    #[test]
+    fn adds_answers_to_headers() {
+        let content = with_answer("47|53\n", 1, "143").unwrap();
+        assert_eq!(content, "part_1: 143\n---\n47|53\n");
+
+        let content = with_answer(&content, 2, "123").unwrap();
+        assert_eq!(content, "part_1: 143\npart_2: 123\n---\n47|53\n");
+
+        let example = Example::parse("05", &content);
+        assert_eq!(example.expected(1), Some("143"));
+        assert_eq!(example.expected(2), Some("123"));
+        assert_eq!(example.input, "47|53\n");
+
+        // answers that were declared already are kept, even if edited by hand.
+        assert_eq!(with_answer("part_1: 7\n---\n47|53\n", 1, "143"), None);
+        assert_eq!(
+            with_answer("width: 11\r\n---\r\np=0,4\r\n", 1, "12"),
+            Some("width: 11\r\npart_1: 12\n---\r\np=0,4\r\n".into())
+        );
+    }
+
+    #[test]
+    fn strips_headers() {
+        assert_eq!(strip_header("part_2: 6\r\n---\r\n....#\r\n"), "....#\r\n");
+        assert_eq!(strip_header("---\n....#"), "....#");
+    }
+}
@@ -0,0 +1,239 @@
+/// Module that keeps an append-only history of stored timings.
+/// Every `time --store` run appends one JSON line with the new timings and metadata about the environment.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::timings::Timings;
+
+static HISTORY_FILE_PATH: &str = "./data/timings_history.jsonl";
+
+/// A single `time --store` run.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the git commit, suffixed with `-dirty` for uncommitted changes.
+    pub commit: Option<String>,
+    pub rustc: Option<String>,
+    pub host: Option<String>,
+    pub timings: Timings,
+}
+
+impl HistoryEntry {
+    /// Create an entry for the given timings, capturing the current time, commit and toolchain.
+    pub fn capture(timings: Timings) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        let rustc = command_output("rustc", &["-vV"]);
+        let rustc_field = |key: &str| {
+            rustc
+                .as_deref()?
+                .lines()
+                .find_map(|l| l.strip_prefix(key))
+                .map(|v| v.trim().to_string())
+        };
+
+        Self {
+            timestamp,
+            commit: git_commit(),
+            rustc: rustc_field("release:"),
+            host: rustc_field("host:"),
+            timings,
+        }
+    }
+}
+
+fn command_output(cmd: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(cmd).args(args).output().ok()?;
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+fn git_commit() -> Option<String> {
+    let hash = command_output("git", &["rev-parse", "--short", "HEAD"])?;
+    let is_dirty = command_output("git", &["status", "--porcelain"]).is_some_and(|s| !s.is_empty());
+
+    if is_dirty {
+        Some(format!("{hash}-dirty"))
+    } else {
+        Some(hash)
+    }
+}
+
+/// Append an entry to the history file.
+pub fn append(entry: &HistoryEntry) -> Result<(), io::Error> {
+    let line = JsonValue::from(entry)
+        .stringify()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_FILE_PATH)?;
+
+    writeln!(file, "{line}")
+}
+
+/// Read all entries from the history file, oldest first. If not present, returns an empty history.
+pub fn read_from_file() -> Result<Vec<HistoryEntry>, String> {
+    let Ok(content) = fs::read_to_string(HISTORY_FILE_PATH) else {
+        return Ok(vec![]);
+    };
+
+    parse(&content)
+}
+
+fn parse(content: &str) -> Result<Vec<HistoryEntry>, String> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(HistoryEntry::try_from)
+        .collect()
+}
+
+/// Format a unix timestamp as an UTC date and time, e.g. `2024-12-14 10:00`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // civil-from-days, see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        (seconds % 3600) / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let optional = |v: &Option<String>| v.clone().map_or(JsonValue::Null, JsonValue::String);
+
+        let mut map = match JsonValue::from(value.timings.clone()) {
+            JsonValue::Object(map) => map,
+            _ => HashMap::new(),
+        };
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("commit".into(), optional(&value.commit));
+        map.insert("rustc".into(), optional(&value.rustc));
+        map.insert("host".into(), optional(&value.host));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("history entry is not valid JSON."))?;
+
+        let map = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = map
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64)
+            .ok_or("Expected history.timestamp to be a number.")?;
+
+        let optional = |key: &str| map.get(key).and_then(|v| v.get::<String>()).cloned();
+
+        Ok(HistoryEntry {
+            timestamp,
+            commit: optional("commit"),
+            rustc: optional("rustc"),
+            host: optional("host"),
+            // NOTE: the entry shares the `data` key with the timings file format.
+            timings: Timings::try_from(value.to_string())?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_timestamp, parse, HistoryEntry};
+    use crate::{
+        day,
+        template::timings::{Timing, Timings},
+    };
+    use tinyjson::JsonValue;
+
+    fn get_mock_entry() -> HistoryEntry {
+        HistoryEntry {
+            timestamp: 1_734_170_400,
+            commit: Some("eda3c77".into()),
+            rustc: Some("1.83.0".into()),
+            host: None,
+            timings: Timings {
+                data: vec![Timing {
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+7,
+                    ..Timing::new(day!(5))
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let line = JsonValue::from(&get_mock_entry()).stringify().unwrap();
+        let entries = parse(&format!("{line}\n{line}\n")).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        let entry = &entries[0];
+        assert_eq!(entry.timestamp, 1_734_170_400);
+        assert_eq!(entry.commit, Some("eda3c77".into()));
+        assert_eq!(entry.rustc, Some("1.83.0".into()));
+        assert_eq!(entry.host, None);
+        assert_eq!(entry.timings.data[0].day, day!(5));
+        assert_eq!(entry.timings.data[0].part_2, Some("20ms".into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_entries() {
+        parse(r#"{ "data": [] }"#).unwrap();
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_734_170_400), "2024-12-14 10:00");
+        assert_eq!(format_timestamp(1_709_210_096), "2024-02-29 12:34");
+    }
+}
@@ -0,0 +1,152 @@
+/// Module that reads the input a solution runs against.
+///
+/// By default this is `data/inputs/{day}.txt`. `cargo solve 05 --input path/to/file` runs against another file,
+/// `--input -` against stdin, and `--example [N]` against the N-th example of the day (the first by default),
+/// see [`template::examples`](crate::template::examples).
+/// Answers for any other input than the puzzle input are never checked against the stored answers or submitted.
+use std::{
+    env,
+    io::{self, Read},
+    process,
+    sync::OnceLock,
+};
+
+use crate::template::{
+    examples::{self, Example},
+    params, read_file, Day,
+};
+
+/// Where the input of a run comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// The puzzle input, `data/inputs/{day}.txt`.
+    Puzzle,
+    File(String),
+    Stdin,
+    /// An example of the day, counted from 1.
+    Example(usize),
+}
+
+impl Source {
+    /// Read the source from `--input <path|->` or `--example [N]`.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let value = |flag: &str| {
+            let index = args.iter().position(|x| x == flag)?;
+            Some(args.get(index + 1).filter(|x| !x.starts_with("--")))
+        };
+
+        match (value("--input"), value("--example")) {
+            (Some(_), Some(_)) => Err("Unexpected --input and --example, pass only one.".into()),
+            (Some(None), _) => Err("Expected a path or `-` after --input.".into()),
+            (Some(Some(path)), None) if path == "-" => Ok(Source::Stdin),
+            (Some(Some(path)), None) => Ok(Source::File(path.clone())),
+            (None, Some(None)) => Ok(Source::Example(1)),
+            (None, Some(Some(n))) => match n.parse() {
+                Ok(0) | Err(_) => Err(format!(
+                    "Unexpected example `{n}`, examples are counted from 1."
+                )),
+                Ok(n) => Ok(Source::Example(n)),
+            },
+            (None, None) => Ok(Source::Puzzle),
+        }
+    }
+}
+
+/// The example that is run, if any. Its expected answers replace the stored answers.
+static EXAMPLE: OnceLock<Example> = OnceLock::new();
+
+/// Read the input of a run, see the module documentation. Called by [`solution!`](crate::solution).
+///
+/// # Panics
+///
+/// Panics if the puzzle input can not be read, like [`read_file`].
+pub fn read(day: Day) -> String {
+    let source = Source::from_args(&env::args().collect::<Vec<_>>()).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let result = match source {
+        Source::Puzzle => return read_file("inputs", day),
+        Source::File(path) => {
+            std::fs::read_to_string(&path).map_err(|e| format!("Failed to read \"{path}\": {e}"))
+        }
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map(|_| input)
+                .map_err(|e| format!("Failed to read stdin: {e}"))
+        }
+        Source::Example(n) => read_example(day, n),
+    };
+
+    result.unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    })
+}
+
+fn read_example(day: Day, n: usize) -> Result<String, String> {
+    let examples = examples::read_examples(day);
+
+    let Some(example) = examples.get(n - 1) else {
+        return Err(format!(
+            "Unexpected example {n}, day {day} has {} examples.",
+            examples.len()
+        ));
+    };
+
+    println!("Running example \"{}\".", example.name);
+
+    params::add_defaults(&example.params);
+    let input = example.input.clone();
+    let _ = EXAMPLE.set(example.clone());
+
+    Ok(input)
+}
+
+/// Whether the run uses another input than the puzzle input.
+pub fn is_custom() -> bool {
+    env::args().any(|x| x == "--input" || x == "--example")
+}
+
+/// The expected answer of the example that is run.
+pub fn expected(part: u8) -> Option<&'static str> {
+    EXAMPLE.get()?.expected(part)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Source;
+
+    fn source(args: &[&str]) -> Result<Source, String> {
+        Source::from_args(&args.iter().map(ToString::to_string).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn reads_source_from_args() {
+        assert_eq!(source(&["05", "--time"]), Ok(Source::Puzzle));
+        assert_eq!(
+            source(&["05", "--input", "other.txt"]),
+            Ok(Source::File("other.txt".into()))
+        );
+        assert_eq!(source(&["05", "--input", "-"]), Ok(Source::Stdin));
+        assert_eq!(source(&["05", "--example"]), Ok(Source::Example(1)));
+        assert_eq!(
+            source(&["05", "--example", "--time"]),
+            Ok(Source::Example(1))
+        );
+        assert_eq!(source(&["05", "--example", "3"]), Ok(Source::Example(3)));
+    }
+
+    #[test]
+    fn rejects_invalid_sources() {
+        assert!(source(&["05", "--input"]).is_err());
+        assert!(source(&["05", "--example", "0"]).is_err());
+        assert!(source(&["05", "--example", "small"]).is_err());
+        assert!(source(&["05", "--input", "-", "--example"]).is_err());
+    }
+}
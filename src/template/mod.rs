@@ -2,13 +2,26 @@ use std::{env, fs};
 
 pub mod aoc_cli;
 pub mod commands;
+pub mod examples;
+pub mod input;
+pub mod ocr;
+pub mod params;
+pub mod registry;
 pub mod runner;
+pub mod trace;
+pub mod viz;
 
 pub use day::*;
 
+mod answers;
 mod day;
+mod history;
+mod perf;
+mod puzzle;
 mod readme_benchmarks;
+mod report;
 mod run_multi;
+mod submissions;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
@@ -16,12 +29,13 @@ pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// The header of an example is stripped, see [`template::examples`](crate::template::examples).
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    strip_example_header(folder, f.expect("could not open input file"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
@@ -33,36 +47,189 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    strip_example_header(folder, f.expect("could not open input file"))
+}
+
+fn strip_example_header(folder: &str, content: String) -> String {
+    if folder == "examples" {
+        examples::strip_header(&content).to_string()
+    } else {
+        content
+    }
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
+/// The day is a number, or a year and a number like `2023/5` for a day of another event.
+///
+/// The input is `data/inputs/{day}.txt` unless another one is passed, see [`template::input`](crate::template::input).
+///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Parts return an `Option` or a `Result` whose error implements `Display`, see [`PartResult`](crate::template::runner::PartResult).
+/// An error is printed in place of the answer and is never submitted.
+///
+/// An optional `parse = func` parameter adds a parse stage: `func` takes the input as `&str` and returns an `Option` or a `Result`.
+/// It runs once, is timed separately, and a reference to the parsed value is handed to each part.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($($day:literal)/+) => {
+        $crate::solution!(@impl ($($day)/+), [part_one, 1] [part_two, 2]);
+    };
+    ($($day:literal)/+, 1) => {
+        $crate::solution!(@impl ($($day)/+), [part_one, 1]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($($day:literal)/+, 2) => {
+        $crate::solution!(@impl ($($day)/+), [part_two, 2]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($($day:literal)/+, parse = $parse:expr) => {
+        $crate::solution!(@impl ($($day)/+), parse = $parse, [part_one, 1] [part_two, 2]);
     };
+    ($($day:literal)/+, 1, parse = $parse:expr) => {
+        $crate::solution!(@impl ($($day)/+), parse = $parse, [part_one, 1]);
+    };
+    ($($day:literal)/+, 2, parse = $parse:expr) => {
+        $crate::solution!(@impl ($($day)/+), parse = $parse, [part_two, 2]);
+    };
+
+    (@impl $day:tt, parse = $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $day);
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        pub fn main() {
+            use $crate::template::runner::*;
+            $crate::template::trace::init(DAY);
+            let input = $crate::template::input::read(DAY);
+            match run_parse($parse, &input) {
+                Some(parsed) => {
+                    $( run_part($func, &parsed, DAY, $part); )*
+                    $crate::template::params::warn_unused();
+                }
+                None => {
+                    $( skip_part($part); )*
+                }
+            }
+        }
+    };
+    (@impl $day:tt, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $day);
+
+        pub fn main() {
+            use $crate::template::runner::*;
+            $crate::template::trace::init(DAY);
+            let input = $crate::template::input::read(DAY);
+            $( run_part($func, &input, DAY, $part); )*
+            $crate::template::params::warn_unused();
+        }
+    };
+
+    (@common $day:tt) => {
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        pub const DAY: $crate::template::Day = $crate::day! $day;
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
+    };
+}
+
+/// Writes a debug message of a solution, formatted like [`format!`].
+/// Enabled with `cargo solve NN -v`, see [`template::trace`](crate::template::trace).
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::template::trace::enabled($crate::template::trace::Level::Debug) {
+            $crate::template::trace::write($crate::template::trace::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+/// Writes a trace message of a solution, formatted like [`format!`].
+/// Enabled with `cargo solve NN -vv`, see [`template::trace`](crate::template::trace).
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::template::trace::enabled($crate::template::trace::Level::Trace) {
+            $crate::template::trace::write($crate::template::trace::Level::Trace, format_args!($($arg)*));
+        }
+    };
+}
+
+/// Generates a test per part that runs every example of the day with an expected answer,
+/// see [`template::examples`](crate::template::examples). Use it inside the `tests` module of a day.
+///
+/// Takes the same optional part and `parse = func` parameters as [`solution!`](crate::solution).
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        $crate::example_tests!(@impl [part_one, 1, test_examples_part_one] [part_two, 2, test_examples_part_two]);
+    };
+    (1) => {
+        $crate::example_tests!(@impl [part_one, 1, test_examples_part_one]);
+    };
+    (2) => {
+        $crate::example_tests!(@impl [part_two, 2, test_examples_part_two]);
+    };
+    (parse = $parse:expr) => {
+        $crate::example_tests!(@impl parse = $parse, [part_one, 1, test_examples_part_one] [part_two, 2, test_examples_part_two]);
+    };
+    (1, parse = $parse:expr) => {
+        $crate::example_tests!(@impl parse = $parse, [part_one, 1, test_examples_part_one]);
+    };
+    (2, parse = $parse:expr) => {
+        $crate::example_tests!(@impl parse = $parse, [part_two, 2, test_examples_part_two]);
+    };
+
+    (@impl parse = $parse:expr, $( [$func:expr, $part:expr, $name:ident] )*) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::template::examples::check_examples(DAY, $part, |example| {
+                    use $crate::template::runner::PartResult;
+                    let parsed = ($parse)(example.input.as_str()).into_result()?;
+                    $func(&parsed).into_result().map(|answer| answer.to_string())
+                });
+            }
+        )*
+    };
+    (@impl $( [$func:expr, $part:expr, $name:ident] )*) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::template::examples::check_examples(DAY, $part, |example| {
+                    use $crate::template::runner::PartResult;
+                    $func(example.input.as_str()).into_result().map(|answer| answer.to_string())
+                });
+            }
+        )*
+    };
+}
+
+/// Compiles the given day modules into a single binary, see [`template::registry`](crate::template::registry).
+///
+/// Every entry maps a module name to the path of the day's bin, relative to the registry bin.
+/// The registry is unavailable with the `dhat-heap` feature, as every day declares its own global allocator.
+#[macro_export]
+macro_rules! registry {
+    ($( $module:ident: $path:literal ),* $(,)?) => {
+        $(
+            #[cfg(not(feature = "dhat-heap"))]
+            #[path = $path]
+            mod $module;
+        )*
 
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            #[cfg(not(feature = "dhat-heap"))]
+            $crate::template::registry::run(&[
+                $(
+                    $crate::template::registry::Solution {
+                        day: $module::DAY,
+                        main: $module::main,
+                    },
+                )*
+            ]);
+
+            #[cfg(feature = "dhat-heap")]
+            $crate::template::registry::run(&[]);
         }
     };
 }
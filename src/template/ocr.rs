@@ -0,0 +1,191 @@
+/// Module that reads the capital letters some puzzles draw with `#` and `.` pixels, e.g. `EHZRUPBL`.
+/// Recognizes the two fonts used by AoC: letters of 4×6 pixels and letters of 6×10 pixels.
+///
+/// Letters of the small font, 6 pixels high. Most letters are 4 pixels wide.
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters of the large font, 10 pixels high and 6 pixels wide.
+#[rustfmt::skip]
+const FONT_10: [(char, &str); 15] = [
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+/// Decode the letters drawn in `art`. Lit pixels are `#` or `█`, every other character is dark.
+/// Returns `None` if the art is not 6 or 10 pixels high, or if any letter is not recognized.
+pub fn decode(art: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c == '#' || c == '█')
+                .collect::<Vec<_>>()
+        })
+        .skip_while(|row| !row.contains(&true))
+        .collect();
+
+    let height = rows.iter().rposition(|row| row.contains(&true))? + 1;
+    let rows = &rows[..height];
+
+    let font: &[(char, &str)] = match height {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(Vec::len).max()?;
+    let is_lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut result = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if !is_lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && is_lit(x) {
+            x += 1;
+        }
+
+        let glyph = rows
+            .iter()
+            .map(|row| {
+                (start..x)
+                    .map(|i| if row.get(i) == Some(&true) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (letter, _) = font.iter().find(|(_, g)| *g == glyph)?;
+        result.push(*letter);
+    }
+
+    (!result.is_empty()).then_some(result)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode, FONT_10, FONT_6};
+
+    /// Draw a word in a font, with a single dark column between letters.
+    fn render(font: &[(char, &str)], word: &str, lit: char, dark: char) -> String {
+        let glyphs: Vec<Vec<&str>> = word
+            .chars()
+            .map(|c| {
+                font.iter()
+                    .find(|(l, _)| *l == c)
+                    .unwrap()
+                    .1
+                    .lines()
+                    .collect()
+            })
+            .collect();
+
+        (0..glyphs[0].len())
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|g| g[y])
+                    .collect::<Vec<_>>()
+                    .join(".")
+                    .replace('#', &lit.to_string())
+                    .replace('.', &dark.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn decodes_small_font() {
+        let alphabet: String = FONT_6.iter().map(|(c, _)| c).collect();
+        assert_eq!(
+            decode(&render(&FONT_6, &alphabet, '#', '.')),
+            Some(alphabet)
+        );
+        assert_eq!(
+            decode(&render(&FONT_6, "HELLO", '█', ' ')),
+            Some("HELLO".into())
+        );
+    }
+
+    #[test]
+    fn decodes_large_font() {
+        let alphabet: String = FONT_10.iter().map(|(c, _)| c).collect();
+        assert_eq!(
+            decode(&format!("\n{}\n\n", render(&FONT_10, &alphabet, '#', '.'))),
+            Some(alphabet)
+        );
+    }
+
+    #[test]
+    fn decodes_puzzle_output() {
+        // The CRT of 2022 day 10: 40 columns of 5-wide cells, the last column of each cell dark.
+        let crt = "\
+###..#....####.####.#..#.#....###..###..
+#..#.#....#....#....#..#.#....#..#.#..#.
+#..#.#....###..###..#..#.#....#..#.###..
+###..#....#....#....#..#.#....###..#..#.
+#....#....#....#....#..#.#....#....#..#.
+#....####.####.#.....##..####.#....###..";
+        assert_eq!(decode(crt), Some("PLEFULPB".into()));
+
+        // The message of 2018 day 10: 6-wide letters, two columns apart.
+        let sky = "\
+#####...#####....####...#....#..#....#..######
+#....#..#....#..#....#..##...#..#....#.......#
+#....#..#....#..#.......##...#...#..#........#
+#....#..#....#..#.......#.#..#...#..#.......#.
+#####...#####...#.......#.#..#....##.......#..
+#..#....#.......#..###..#..#.#....##......#...
+#...#...#.......#....#..#..#.#...#..#....#....
+#...#...#.......#....#..#...##...#..#...#.....
+#....#..#.......#...##..#...##..#....#..#.....
+#....#..#........###.#..#....#..#....#..######";
+        assert_eq!(decode(sky), Some("RPGNXZ".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_art() {
+        assert_eq!(decode("143"), None);
+        assert_eq!(decode("#.#\n.#.\n#.#"), None);
+        assert_eq!(decode("####\n####\n####\n####\n####\n####"), None);
+    }
+}
@@ -0,0 +1,180 @@
+/// Module that hands per-run parameters to solutions, e.g. the grid size that differs between the example and the real input.
+///
+/// A part reads a parameter with [`param`], which falls back to the default of the real input.
+/// Parameters are overridden from the command line with `cargo solve 14 --param width=11`,
+/// and by the header of an example, see [`template::examples`](crate::template::examples).
+/// Parameters that are given but never read, e.g. a typo like `widht`, are reported by [`warn_unused`].
+///
+/// NOTE: parameters are kept per thread instead of being passed to the parts by [`solution!`](crate::solution),
+/// so every part keeps its `fn(&str) -> Option<T>` signature and days without parameters are unaffected.
+use std::{cell::RefCell, collections::HashSet, env, fmt::Debug, str::FromStr};
+
+#[derive(Default)]
+struct Params {
+    values: Vec<(String, String)>,
+    /// The names passed to [`param`], whether they were given or not.
+    read: HashSet<String>,
+}
+
+impl Params {
+    fn from_values(values: Vec<(String, String)>) -> Self {
+        Params {
+            values,
+            ..Params::default()
+        }
+    }
+}
+
+thread_local! {
+    /// Parameters of the current thread, read from the command line on first use.
+    static PARAMS: RefCell<Option<Params>> = const { RefCell::new(None) };
+}
+
+fn with_current<R>(f: impl FnOnce(&mut Params) -> R) -> R {
+    PARAMS.with_borrow_mut(|params| {
+        f(params.get_or_insert_with(|| {
+            Params::from_values(from_args(&env::args().collect::<Vec<_>>()))
+        }))
+    })
+}
+
+/// Read a parameter, or `default` if it is not set.
+///
+/// # Panics
+///
+/// Panics if the value of the parameter can not be parsed to `T`.
+pub fn param<T: FromStr>(name: &str, default: T) -> T
+where
+    T::Err: Debug,
+{
+    with_current(|params| {
+        params.read.insert(name.to_string());
+
+        match params.values.iter().find(|(key, _)| key == name) {
+            Some((_, value)) => value.parse().unwrap_or_else(|e| {
+                panic!("Invalid value `{value}` for parameter `{name}`: {e:?}")
+            }),
+            None => default,
+        }
+    })
+}
+
+/// The parameters that were given but not read by [`param`] so far.
+pub fn unused() -> Vec<String> {
+    with_current(|params| {
+        params
+            .values
+            .iter()
+            .filter(|(key, _)| !params.read.contains(key))
+            .map(|(key, _)| key.clone())
+            .collect()
+    })
+}
+
+/// Warn about parameters that were given but never read. Called by [`solution!`](crate::solution) after all parts ran.
+pub fn warn_unused() {
+    for key in unused() {
+        eprintln!("Warning: parameter `{key}` was given, but not read by the solution.");
+    }
+}
+
+/// Run `f` with the given parameters instead of the ones from the command line.
+pub fn with_params<R>(params: &[(impl AsRef<str>, impl AsRef<str>)], f: impl FnOnce() -> R) -> R {
+    let params = params
+        .iter()
+        .map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string()))
+        .collect();
+
+    let previous = PARAMS.replace(Some(Params::from_values(params)));
+    let result = f();
+    PARAMS.set(previous);
+
+    result
+}
+
+/// Add parameters that apply unless they are set on the command line, e.g. the header of an example run with `--example`.
+pub(crate) fn add_defaults(defaults: &[(String, String)]) {
+    with_current(|params| {
+        for (key, value) in defaults {
+            if !params.values.iter().any(|(k, _)| k == key) {
+                params.values.push((key.clone(), value.clone()));
+            }
+        }
+    });
+}
+
+/// Collect all `--param key=value` arguments.
+pub fn from_args(args: &[String]) -> Vec<(String, String)> {
+    args.windows(2)
+        .filter(|w| w[0] == "--param")
+        .filter_map(|w| w[1].split_once('='))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{add_defaults, from_args, param, unused, with_params};
+
+    #[test]
+    fn reads_params_from_args() {
+        let args = [
+            "14",
+            "--param",
+            "width=11",
+            "--time",
+            "--param",
+            "height = 7",
+        ]
+        .map(String::from);
+        assert_eq!(
+            from_args(&args),
+            vec![
+                ("width".to_string(), "11".to_string()),
+                ("height".to_string(), "7".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn overrides_defaults() {
+        assert_eq!(param("width", 101), 101);
+
+        let (width, height) = with_params(&[("width", "11")], || {
+            (param("width", 101_isize), param("height", 103_isize))
+        });
+        assert_eq!((width, height), (11, 103));
+
+        assert_eq!(param("width", 101), 101);
+    }
+
+    #[test]
+    fn keeps_params_over_defaults() {
+        let params = [("width", "11")];
+        let defaults = [("width", "7"), ("height", "5")].map(|(k, v)| (k.into(), v.into()));
+
+        let (width, height) = with_params(&params, || {
+            add_defaults(&defaults);
+            (param("width", 101), param("height", 103))
+        });
+        assert_eq!((width, height), (11, 5));
+    }
+
+    #[test]
+    fn finds_unused_params() {
+        let unused = with_params(&[("width", "11"), ("widht", "11")], || {
+            param("width", 101);
+            param("height", 103);
+            unused()
+        });
+        assert_eq!(unused, vec!["widht".to_string()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_values() {
+        with_params(&[("width", "wide")], || param("width", 101));
+    }
+}
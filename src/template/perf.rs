@@ -0,0 +1,179 @@
+/// Module that reads hardware performance counters via `perf_event_open`.
+/// Only supported on Linux, other platforms (and kernels that forbid access) report an error when opening the counters.
+use crate::template::runner::PerfCounters;
+
+/// A set of opened, disabled counters for the current thread.
+pub struct Counters {
+    #[cfg(target_os = "linux")]
+    events: [linux::Event; 4],
+}
+
+impl Counters {
+    #[cfg(target_os = "linux")]
+    pub fn open() -> Result<Self, String> {
+        use linux::{
+            Event, PERF_COUNT_HW_BRANCH_MISSES, PERF_COUNT_HW_CACHE_MISSES,
+            PERF_COUNT_HW_CPU_CYCLES, PERF_COUNT_HW_INSTRUCTIONS,
+        };
+
+        Ok(Self {
+            events: [
+                Event::open(PERF_COUNT_HW_INSTRUCTIONS)?,
+                Event::open(PERF_COUNT_HW_CPU_CYCLES)?,
+                Event::open(PERF_COUNT_HW_CACHE_MISSES)?,
+                Event::open(PERF_COUNT_HW_BRANCH_MISSES)?,
+            ],
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn open() -> Result<Self, String> {
+        Err("hardware counters are only supported on Linux.".into())
+    }
+
+    /// Count the events of `f`. Counts are accumulated across calls.
+    pub fn measure<T>(&mut self, f: impl FnOnce() -> T) -> Result<T, String> {
+        #[cfg(target_os = "linux")]
+        {
+            self.events.iter().try_for_each(linux::Event::enable)?;
+            let result = f();
+            self.events.iter().try_for_each(linux::Event::disable)?;
+            Ok(result)
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        Ok(f())
+    }
+
+    /// Read the counts accumulated so far, divided by the number of `runs`.
+    pub fn read(&mut self, runs: u64) -> Result<PerfCounters, String> {
+        #[cfg(target_os = "linux")]
+        {
+            let mut counts = [0; 4];
+            for (count, event) in counts.iter_mut().zip(&mut self.events) {
+                *count = event.read()? / runs.max(1);
+            }
+
+            let [instructions, cycles, cache_misses, branch_misses] = counts;
+
+            Ok(PerfCounters {
+                instructions,
+                cycles,
+                cache_misses,
+                branch_misses,
+            })
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = runs;
+            Err("hardware counters are only supported on Linux.".into())
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::{
+        fs::File,
+        io::{self, Read},
+        os::fd::{AsRawFd, FromRawFd},
+    };
+
+    // see: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+    const PERF_TYPE_HARDWARE: u32 = 0;
+    pub const PERF_COUNT_HW_CPU_CYCLES: u64 = 0;
+    pub const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+    pub const PERF_COUNT_HW_CACHE_MISSES: u64 = 3;
+    pub const PERF_COUNT_HW_BRANCH_MISSES: u64 = 5;
+
+    const PERF_FLAG_FD_CLOEXEC: libc::c_ulong = 8;
+
+    const PERF_EVENT_IOC_ENABLE: libc::c_ulong = 0x2400;
+    const PERF_EVENT_IOC_DISABLE: libc::c_ulong = 0x2401;
+
+    // attribute flags, see `struct perf_event_attr` in `linux/perf_event.h`.
+    const FLAG_DISABLED: u64 = 1;
+    const FLAG_EXCLUDE_KERNEL: u64 = 1 << 5;
+    const FLAG_EXCLUDE_HV: u64 = 1 << 6;
+
+    /// The first published version of `struct perf_event_attr` (`PERF_ATTR_SIZE_VER0`), which every kernel accepts.
+    #[repr(C)]
+    #[derive(Default)]
+    struct PerfEventAttr {
+        kind: u32,
+        size: u32,
+        config: u64,
+        sample_period: u64,
+        sample_type: u64,
+        read_format: u64,
+        flags: u64,
+        wakeup_events: u32,
+        bp_type: u32,
+        config1: u64,
+    }
+
+    /// A single counter, measuring user space of the calling thread on any CPU.
+    pub struct Event(File);
+
+    impl Event {
+        pub fn open(config: u64) -> Result<Self, String> {
+            let attr = PerfEventAttr {
+                kind: PERF_TYPE_HARDWARE,
+                size: std::mem::size_of::<PerfEventAttr>() as u32,
+                config,
+                // NOTE: excluding the kernel allows access with the default `perf_event_paranoid` setting.
+                flags: FLAG_DISABLED | FLAG_EXCLUDE_KERNEL | FLAG_EXCLUDE_HV,
+                ..PerfEventAttr::default()
+            };
+
+            // SAFETY: `attr` is a valid `perf_event_attr` that outlives the call.
+            let fd = unsafe {
+                libc::syscall(
+                    libc::SYS_perf_event_open,
+                    &attr as *const PerfEventAttr,
+                    0,
+                    -1,
+                    -1,
+                    PERF_FLAG_FD_CLOEXEC,
+                )
+            };
+
+            if fd < 0 {
+                return Err(format!(
+                    "perf_event_open failed: {}",
+                    io::Error::last_os_error()
+                ));
+            }
+
+            // SAFETY: the syscall returned a new file descriptor that nothing else owns.
+            #[allow(clippy::cast_possible_truncation)]
+            Ok(Self(unsafe { File::from_raw_fd(fd as i32) }))
+        }
+
+        pub fn enable(&self) -> Result<(), String> {
+            self.ioctl(PERF_EVENT_IOC_ENABLE)
+        }
+
+        pub fn disable(&self) -> Result<(), String> {
+            self.ioctl(PERF_EVENT_IOC_DISABLE)
+        }
+
+        pub fn read(&mut self) -> Result<u64, String> {
+            let mut buf = [0; 8];
+            self.0.read_exact(&mut buf).map_err(|e| e.to_string())?;
+            Ok(u64::from_ne_bytes(buf))
+        }
+
+        fn ioctl(&self, request: libc::c_ulong) -> Result<(), String> {
+            // SAFETY: the request takes no argument and the descriptor is open.
+            let res = unsafe { libc::ioctl(self.0.as_raw_fd(), request as _, 0) };
+
+            if res < 0 {
+                Err(format!("ioctl failed: {}", io::Error::last_os_error()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+/// Module that reads parts of the puzzle description downloaded to `data/puzzles/{day}.md`.
+use std::fs;
+
+use crate::template::Day;
+
+/// A fenced code block of the puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    pub content: String,
+    /// Whether the text leading up to the block says "For example".
+    pub is_example: bool,
+}
+
+pub fn get_path(day: Day) -> String {
+    format!("data/puzzles/{day}.md")
+}
+
+pub fn read(day: Day) -> Option<String> {
+    fs::read_to_string(get_path(day)).ok()
+}
+
+/// Collect all fenced code blocks of a puzzle description, in order.
+pub fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut text = String::new();
+    let mut code: Option<String> = None;
+
+    for line in markdown.lines() {
+        let is_fence = line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~");
+
+        match (&mut code, is_fence) {
+            (None, true) => code = Some(String::new()),
+            (None, false) => {
+                text.push_str(line);
+                text.push('\n');
+            }
+            (Some(_), true) => {
+                blocks.push(CodeBlock {
+                    content: code.take().unwrap_or_default(),
+                    is_example: text.to_lowercase().contains("for example"),
+                });
+                text.clear();
+            }
+            (Some(content), false) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+        }
+    }
+
+    blocks
+}
+
+/// The answers of the example for both parts, as far as the puzzle description is available.
+/// The example answer is usually the last number in emphasized code of a part, e.g. `` `*143*` ``.
+pub fn example_answers(markdown: &str) -> [Option<String>; 2] {
+    let (part_1, part_2) = match markdown.find("Part Two ---") {
+        Some(index) => (&markdown[..index], Some(&markdown[index..])),
+        None => (markdown, None),
+    };
+
+    let answer = |text: &str| {
+        emphasized_code(text)
+            .into_iter()
+            .rfind(|code| code.parse::<i64>().is_ok())
+    };
+
+    [answer(part_1), part_2.and_then(answer)]
+}
+
+/// Collect the content of all emphasized inline code, outside of code blocks.
+/// Both `` `*code*` `` and `` *`code`* `` are recognized.
+pub fn emphasized_code(markdown: &str) -> Vec<String> {
+    let mut result = vec![];
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+            in_block = !in_block;
+            continue;
+        }
+
+        if in_block {
+            continue;
+        }
+
+        // odd segments are the content of inline code spans.
+        let segments: Vec<&str> = line.split('`').collect();
+
+        for i in (1..segments.len().saturating_sub(1)).step_by(2) {
+            let code = segments[i].trim();
+
+            let is_emphasized = (code.len() > 2 && code.starts_with('*') && code.ends_with('*'))
+                || (segments[i - 1].ends_with('*') && segments[i + 1].starts_with('*'));
+
+            if is_emphasized {
+                result.push(code.trim_matches('*').trim().to_string());
+            }
+        }
+    }
+
+    result
+}
+
+/// The index of the block that most likely holds the example input:
+/// the first block that follows "For example", or the first block otherwise.
+pub fn default_example(blocks: &[CodeBlock]) -> Option<usize> {
+    blocks
+        .iter()
+        .position(|b| b.is_example)
+        .or((!blocks.is_empty()).then_some(0))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{code_blocks, default_example, emphasized_code, example_answers};
+
+    static PUZZLE: &str = "\
+\\--- Day 1: Historian Hysteria ---
+----------
+
+Use `cargo run` to find the list:
+
+```
+not an example
+```
+
+For example:
+
+```
+3   4
+4   3
+```
+
+Maybe the lists are only off by a small amount!
+
+~~~
+1 2
+~~~
+";
+
+    #[test]
+    fn finds_code_blocks() {
+        let blocks = code_blocks(PUZZLE);
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].content, "not an example\n");
+        assert_eq!(blocks[1].content, "3   4\n4   3\n");
+        assert!(blocks[1].is_example);
+        assert!(!blocks[2].is_example);
+        assert_eq!(default_example(&blocks), Some(1));
+    }
+
+    #[test]
+    fn falls_back_to_first_block() {
+        let blocks = code_blocks("```\n1\n```\n");
+        assert_eq!(default_example(&blocks), Some(0));
+        assert_eq!(default_example(&[]), None);
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        let text = "In `*one*` or *`two`*, but not in `three` or *four*.\n```\n`*five*`\n```\n";
+        assert_eq!(emphasized_code(text), vec!["one", "two"]);
+    }
+
+    #[test]
+    fn finds_example_answers() {
+        let part_1 = "\\--- Day 5: Print Queue ---\nThe middle page is `*61*`.\nAdding these produces `*143*`.\n";
+        assert_eq!(example_answers(part_1), [Some("143".into()), None]);
+
+        let both =
+            format!("{part_1}\\--- Part Two ---\nThe answer is *`123`*, read the `*rules*`.\n");
+        assert_eq!(
+            example_answers(&both),
+            [Some("143".into()), Some("123".into())]
+        );
+    }
+}
@@ -27,7 +27,7 @@ pub struct TablePosition {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    format!("./src/bin/{}.rs", day.bin_name())
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -55,23 +55,48 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // NOTE: heap columns are only rendered once heap stats have been collected for any day.
+    let has_heap = timings
+        .data
+        .iter()
+        .any(|t| t.parse_heap.is_some() || t.part_1_heap.is_some() || t.part_2_heap.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if has_heap {
+        lines.push(
+            "| Day | Parse | Part 1 | Part 2 | Parse Heap | Part 1 Heap | Part 2 Heap |".into(),
+        );
+        lines.push("| :---: | :---: | :---: | :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+        // NOTE: days of other events are labelled with their year.
+        let label = match timing.day.year() {
+            Some(year) => format!("{year} Day {}", timing.day.into_inner()),
+            None => format!("Day {}", timing.day.into_inner()),
+        };
+        let mut line = format!(
+            "| [{}]({}) | `{}` | `{}` | `{}` |",
+            label,
             path,
+            timing.parse.unwrap_or_else(|| "-".into()),
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+
+        if has_heap {
+            for heap in [timing.parse_heap, timing.part_1_heap, timing.part_2_heap] {
+                let cell = heap.map_or_else(|| "-".into(), |h| h.to_string());
+                line.push_str(&format!(" `{cell}` |"));
+            }
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -100,28 +125,31 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day, template::runner::HeapStats, template::timings::Timing, template::timings::Timings,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
+                    parse: Some("5ms".into()),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -167,11 +195,11 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +208,46 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_heap_benchmarks() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_heap = Some(HeapStats {
+            peak_bytes: 2048,
+            total_allocations: 4,
+            total_bytes: 4096,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+
+        assert_eq!(
+            s.contains(
+                "| Day | Parse | Part 1 | Part 2 | Parse Heap | Part 1 Heap | Part 2 Heap |"
+            ),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` | `-` | `2.0 KiB peak, 4 allocs, 4.0 KiB total` | `-` |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 4](./src/bin/04.rs) | `-` | `40ms` | `50ms` | `-` | `-` | `-` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_of_other_events() {
+        let mut timings = get_mock_timings();
+        timings.data[2].day = day!(2023 / 4);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+
+        assert_eq!(
+            s.contains("| [2023 Day 4](./src/bin/2023-04.rs) | `-` | `40ms` | `50ms` |"),
+            true
+        );
+    }
 }
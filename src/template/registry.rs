@@ -0,0 +1,98 @@
+/// Module that dispatches to the solutions collected by the `registry` bin.
+/// All days are compiled into one binary, so that `cargo all` and `cargo time` only need a single build.
+///
+/// NOTE: the binary is built once and then started directly for every day, without going through cargo.
+/// Days do not run in the process of `cargo all` itself: the watchdog of a timed out part and a panicking day end their process,
+/// which must not take down the remaining days.
+use std::{env, process};
+
+use crate::template::Day;
+
+pub static REGISTRY_BIN: &str = "registry";
+pub static REGISTRY_PATH: &str = "./src/bin/registry.rs";
+
+/// A solution compiled into the registry.
+pub struct Solution {
+    pub day: Day,
+    /// The `main` function generated by [`solution!`](crate::solution).
+    pub main: fn(),
+}
+
+/// Entry point of the registry bin. Runs the day passed as the first argument in-process.
+/// All other arguments are left for the runner, e.g. `registry 05 --time`.
+pub fn run(solutions: &[Solution]) {
+    let Some(day) = env::args().nth(1).and_then(|x| x.parse::<Day>().ok()) else {
+        eprintln!("Usage: registry <day> [args]");
+        process::exit(1);
+    };
+
+    match solutions.iter().find(|s| s.day == day) {
+        Some(solution) => (solution.main)(),
+        None => {
+            eprintln!("Day {day} is not registered in \"{REGISTRY_PATH}\".");
+            process::exit(1);
+        }
+    }
+}
+
+/// Add a day to the source of the registry bin. Returns `None` if the day is registered already.
+/// Entries are kept sorted, new days are inserted before the first entry of a later day.
+pub fn add_entry(content: &str, day: Day) -> Option<String> {
+    let bin = day.bin_name();
+    let entry = format!("    day_{}: \"{bin}.rs\",", bin.replace('-', "_"));
+
+    if content.lines().any(|l| l == entry) {
+        return None;
+    }
+
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let pos = lines
+        .iter()
+        .position(|l| l.starts_with("    day_") && *l > entry.as_str())
+        .or_else(|| lines.iter().rposition(|l| *l == "}"))?;
+
+    lines.insert(pos, &entry);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::add_entry;
+    use crate::day;
+
+    static REGISTRY: &str =
+        "advent_of_code::registry! {\n    day_05: \"05.rs\",\n    day_07: \"07.rs\",\n}\n";
+
+    #[test]
+    fn adds_entries_in_order() {
+        let content = add_entry(REGISTRY, day!(6)).unwrap();
+        assert_eq!(
+            content,
+            "advent_of_code::registry! {\n    day_05: \"05.rs\",\n    day_06: \"06.rs\",\n    day_07: \"07.rs\",\n}\n"
+        );
+
+        let content = add_entry(&content, day!(25)).unwrap();
+        assert_eq!(content.lines().nth(4), Some("    day_25: \"25.rs\","));
+
+        let content = add_entry(&content, day!(1)).unwrap();
+        assert_eq!(content.lines().nth(1), Some("    day_01: \"01.rs\","));
+    }
+
+    #[test]
+    fn adds_days_of_other_events() {
+        let content = add_entry(REGISTRY, day!(2023 / 5)).unwrap();
+        assert_eq!(
+            content.lines().nth(3),
+            Some("    day_2023_05: \"2023-05.rs\",")
+        );
+    }
+
+    #[test]
+    fn skips_registered_days() {
+        assert_eq!(add_entry(REGISTRY, day!(7)), None);
+    }
+}
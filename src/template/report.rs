@@ -0,0 +1,292 @@
+/// Machine-readable protocol between `run_multi` and the solution binaries.
+/// When `AOC_REPORT_PATH` is set, a solution binary appends one JSON line per executed part to that file.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::runner::{BenchStats, HeapStats, PerfCounters};
+
+pub static REPORT_PATH_ENV: &str = "AOC_REPORT_PATH";
+
+/// Outcome of a single solution part. Part `0` denotes the parse stage, which never carries an answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub part: u8,
+    pub success: bool,
+    pub timed_out: bool,
+    pub answer: Option<String>,
+    /// The error message of a part that returned an `Err`.
+    pub error: Option<String>,
+    pub stats: BenchStats,
+    /// Heap usage of the first run, only present when profiled with `--heap`.
+    pub heap: Option<HeapStats>,
+    /// Hardware event counts, only present when run with `--perf` and counters are available.
+    pub counters: Option<PerfCounters>,
+}
+
+/// Append a report to the file selected by the parent process. Does nothing when run standalone.
+pub fn emit(report: &PartReport) -> io::Result<()> {
+    let Ok(path) = env::var(REPORT_PATH_ENV) else {
+        return Ok(());
+    };
+
+    let line = JsonValue::from(report)
+        .stringify()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+/// Read all reports from a report file. A missing file yields no reports.
+pub fn read(path: &Path) -> Result<Vec<PartReport>, String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+
+    parse(&content)
+}
+
+pub fn parse(content: &str) -> Result<Vec<PartReport>, String> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).or(Err("report line is not valid JSON."))?;
+            PartReport::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("success".into(), JsonValue::Boolean(value.success));
+        map.insert("timed_out".into(), JsonValue::Boolean(value.timed_out));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "error".into(),
+            value
+                .error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.stats.median.as_nanos() as f64),
+        );
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert("stats".into(), JsonValue::from(value.stats));
+        map.insert(
+            "heap".into(),
+            value.heap.map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "counters".into(),
+            value.counters.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .ok_or("Expected report.part to be a number.")?;
+
+        let success = json
+            .get("success")
+            .and_then(|v| v.get::<bool>().copied())
+            .ok_or("Expected report.success to be a boolean.")?;
+
+        let timed_out = json
+            .get("timed_out")
+            .and_then(|v| v.get::<bool>().copied())
+            .ok_or("Expected report.timed_out to be a boolean.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| {
+                if v.is_null() {
+                    Some(None)
+                } else {
+                    v.get::<String>().cloned().map(Some)
+                }
+            })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let error = match json.get("error") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .cloned()
+                    .ok_or("Expected report.error to be null or string.")?,
+            ),
+            _ => None,
+        };
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected report to have key `stats`.")
+            .map(BenchStats::try_from)??;
+
+        let heap = match json.get("heap") {
+            Some(v) if !v.is_null() => Some(HeapStats::try_from(v)?),
+            _ => None,
+        };
+
+        let counters = match json.get("counters") {
+            Some(v) if !v.is_null() => Some(PerfCounters::try_from(v)?),
+            _ => None,
+        };
+
+        Ok(PartReport {
+            part,
+            success,
+            timed_out,
+            answer,
+            error,
+            stats,
+            heap,
+            counters,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, PartReport};
+    use crate::template::runner::{BenchStats, HeapStats, PerfCounters};
+    use std::{collections::HashMap, time::Duration};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn round_trips_reports() {
+        let reports = [
+            PartReport {
+                part: 0,
+                success: true,
+                timed_out: false,
+                answer: None,
+                error: None,
+                stats: BenchStats::single(Duration::from_micros(3)),
+                heap: Some(HeapStats {
+                    peak_bytes: 1024,
+                    total_allocations: 3,
+                    total_bytes: 2048,
+                }),
+                counters: None,
+            },
+            PartReport {
+                part: 1,
+                success: true,
+                timed_out: false,
+                answer: Some("@ ( ) ms (2s @ 5 samples)\n#..#".into()),
+                error: None,
+                stats: BenchStats::from_samples(
+                    Duration::from_millis(1),
+                    &[Duration::from_nanos(74_130), Duration::from_nanos(74_131)],
+                ),
+                heap: None,
+                counters: Some(PerfCounters {
+                    instructions: 1_200_000,
+                    cycles: 800_000,
+                    cache_misses: 120,
+                    branch_misses: 3_400,
+                }),
+            },
+            PartReport {
+                part: 2,
+                success: false,
+                timed_out: true,
+                answer: None,
+                error: None,
+                stats: BenchStats::single(Duration::from_nanos(12)),
+                heap: None,
+                counters: None,
+            },
+        ];
+
+        let content = reports
+            .iter()
+            .map(|r| JsonValue::from(r).stringify().unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(parse(&content).unwrap(), reports);
+    }
+
+    #[test]
+    fn rejects_malformed_reports() {
+        let report = PartReport {
+            part: 1,
+            success: true,
+            timed_out: false,
+            answer: Some("42".into()),
+            error: None,
+            stats: BenchStats::single(Duration::from_micros(3)),
+            heap: None,
+            counters: None,
+        };
+
+        let with = |key: &str, value: JsonValue| {
+            let mut json = JsonValue::from(&report);
+            let map: &mut HashMap<String, JsonValue> = json.get_mut().unwrap();
+            map.insert(key.into(), value);
+            parse(&json.stringify().unwrap())
+        };
+
+        assert_eq!(
+            with("answer", JsonValue::String("42".into())),
+            Ok(vec![report.clone()])
+        );
+        assert_eq!(
+            with("answer", JsonValue::Number(42.0)),
+            Err("Expected report.answer to be null or string.".into())
+        );
+        assert_eq!(
+            with("part", JsonValue::String("1".into())),
+            Err("Expected report.part to be a number.".into())
+        );
+        assert_eq!(
+            with("success", JsonValue::Null),
+            Err("Expected report.success to be a boolean.".into())
+        );
+        assert_eq!(
+            with("error", JsonValue::Boolean(true)),
+            Err("Expected report.error to be null or string.".into())
+        );
+    }
+}
@@ -1,41 +1,111 @@
-use std::{collections::HashSet, io};
-
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
+use crate::template::{
+    report::PartReport, runner::BenchConfig, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+use child_commands::{OutputMode, SolutionOutput};
+
+use super::timings::{Timing, Timings};
+
+/// Run the solutions for a set of days. Days are benched with the given config if `bench` is set.
+/// If `timeout` is set, every part is limited to it and days that exceed it are reported as timed out.
+///
+/// Up to `jobs` days run concurrently. Their output is buffered and printed in order of days.
+/// Benchmarks always run one day at a time, so that concurrent days do not distort timings.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    bench: Option<BenchConfig>,
+    timeout: Option<Duration>,
+    jobs: usize,
+) -> Option<Timings> {
+    let mut state = RunState {
+        timings: Vec::with_capacity(days_to_run.len()),
+        timed_out_days: vec![],
+        failed_days: vec![],
+        need_space: false,
+    };
+
+    // NOTE: days are not run in-process. The registry is built once and started directly for every day,
+    // which skips cargo, but a panicking or timed out day still only ends its own process.
+    // A day that does not compile fails the registry build, fall back to building each day on its own.
+    let registry = child_commands::build_registry(is_release).unwrap();
 
-    let mut need_space = false;
+    if registry.is_none() {
+        eprintln!("Failed to build the registry, falling back to per-day binaries.\n");
+    }
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let mut days: Vec<Day> = days_to_run.iter().copied().collect();
+    days.sort_unstable();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let jobs = if bench.is_some() { 1 } else { jobs.max(1) };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let run = |day: Day, output_mode: OutputMode| {
+        child_commands::run_solution(
+            day,
+            bench,
+            is_release,
+            timeout,
+            registry.as_deref(),
+            output_mode,
+        )
+        .unwrap()
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    if jobs == 1 {
+        for day in days {
+            state.print_header(day);
+            let output = run(day, OutputMode::Forward);
+            state.handle_output(day, &output, bench, timeout);
+        }
+    } else {
+        run_parallel(
+            &days,
+            jobs,
+            |day| run(day, OutputMode::Buffer),
+            |day, output| {
+                state.print_header(day);
+                output.print_buffered();
+                state.handle_output(day, &output, bench, timeout);
+            },
+        );
+    }
+
+    if !state.timed_out_days.is_empty() {
+        let days = state
+            .timed_out_days
+            .iter()
+            .map(|day| format!("Day {day}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("\n{ANSI_BOLD}Timed out:{ANSI_RESET} {days}");
+    }
 
-    if is_timed {
-        let timings = Timings { data: timings };
+    if !state.failed_days.is_empty() {
+        let days = state
+            .failed_days
+            .iter()
+            .map(|day| format!("Day {day}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("\n{ANSI_BOLD}Failed:{ANSI_RESET} {days}");
+    }
+
+    if bench.is_some() {
+        let timings = Timings {
+            data: state.timings,
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
@@ -46,11 +116,128 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// Run `run` for every day on up to `jobs` threads.
+/// `handle` is called on the calling thread for every day in order, as soon as all previous days have been handled.
+pub fn run_parallel<T: Send>(
+    days: &[Day],
+    jobs: usize,
+    run: impl Fn(Day) -> T + Sync,
+    mut handle: impl FnMut(Day, T),
+) {
+    let next_day = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let tx = tx.clone();
+            let (next_day, run) = (&next_day, &run);
+
+            s.spawn(move || loop {
+                let i = next_day.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(i) else {
+                    break;
+                };
+                if tx.send((i, run(*day))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(tx);
+
+        let mut pending = HashMap::new();
+        let mut next_handle = 0;
+
+        for (i, result) in rx {
+            pending.insert(i, result);
+
+            while let Some(result) = pending.remove(&next_handle) {
+                handle(days[next_handle], result);
+                next_handle += 1;
+            }
+        }
+    });
+}
+
+/// Results collected while running a set of days.
+struct RunState {
+    timings: Vec<Timing>,
+    timed_out_days: Vec<Day>,
+    /// Days with a part that returned an error or panicked.
+    failed_days: Vec<Day>,
+    need_space: bool,
+}
+
+impl RunState {
+    fn print_header(&mut self, day: Day) {
+        if self.need_space {
+            println!();
+        }
+        self.need_space = true;
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+    }
+
+    fn handle_output(
+        &mut self,
+        day: Day,
+        output: &SolutionOutput,
+        bench: Option<BenchConfig>,
+        timeout: Option<Duration>,
+    ) {
+        if output.timed_out {
+            println!("Timed out.");
+            self.timed_out_days.push(day);
+        }
+
+        if output.reports.is_empty() && output.panicked {
+            // NOTE: the bin panicked before any part finished, e.g. while parsing.
+            println!("Panicked.");
+            self.failed_days.push(day);
+        } else if output.reports.is_empty() && !output.timed_out {
+            println!("Not solved.");
+        } else {
+            let mut val = child_commands::timing_from_reports(&output.reports, day);
+            val.timed_out |= output.timed_out;
+            // NOTE: a part that panics writes no report.
+            val.failed |= output.panicked;
+
+            if val.failed {
+                self.failed_days.push(day);
+            }
+
+            if bench.is_some_and(|b| b.heap) && !output.timed_out {
+                let heap = child_commands::run_heap_profile(day, timeout).unwrap();
+                print_heap_reports(&heap.reports);
+                child_commands::apply_heap_reports(&mut val, &heap.reports);
+            }
+
+            self.timings.push(val);
+        }
+    }
+}
+
+fn print_heap_reports(reports: &[PartReport]) {
+    for report in reports {
+        if let Some(heap) = report.heap {
+            let label = if report.part == 0 {
+                "Parse".to_string()
+            } else {
+                format!("Part {}", report.part)
+            };
+            println!("{label} heap: {ANSI_ITALIC}{heap}{ANSI_RESET}");
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    Report(String),
+    Build(String),
 }
 
 impl From<std::io::Error> for Error {
@@ -61,46 +248,224 @@ impl From<std::io::Error> for Error {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    format!("./src/bin/{}.rs", day.bin_name())
+}
+
+/// Time a day may take before its bin is killed, derived from the per-part timeout.
+/// Covers the parse stage and both parts, plus some slack for process startup.
+#[must_use]
+pub fn day_timeout(part_timeout: Duration) -> Duration {
+    part_timeout * 3 + Duration::from_secs(5)
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// All solutions live in isolated binaries, which are also collected into the registry bin.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading the reports they emit.
+///
+/// Every day still runs in a process of its own, so that a day that panics or exceeds its timeout does not affect other days.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{day_timeout, get_path_for_bin, Error};
+    use crate::template::{
+        registry::REGISTRY_BIN,
+        report::{self, PartReport, REPORT_PATH_ENV},
+        runner::BenchConfig,
+        Day,
+    };
     use std::{
+        collections::HashMap,
+        env, fs,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{self, Command, Stdio},
+        sync::{Arc, Mutex},
         thread,
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
+
+    /// Reports collected from a solution bin.
+    #[derive(Debug, Default)]
+    pub struct SolutionOutput {
+        pub reports: Vec<PartReport>,
+        /// Set if a part hit its timeout or the bin was killed for exceeding the day timeout.
+        pub timed_out: bool,
+        /// Whether the bin exited successfully, i.e. did not panic.
+        pub success: bool,
+        /// Whether the bin ran and exited with an error without timing out, e.g. because a part panicked.
+        /// Unlike `!success`, not set for days that were not run at all.
+        pub panicked: bool,
+        /// Output of the bin, only collected with [`OutputMode::Buffer`].
+        pub output: Vec<OutputLine>,
+    }
+
+    impl SolutionOutput {
+        /// Print the buffered output of the bin.
+        pub fn print_buffered(&self) {
+            for line in &self.output {
+                match line {
+                    OutputLine::Stdout(line) => println!("{line}"),
+                    OutputLine::Stderr(line) => eprintln!("{line}"),
+                }
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum OutputLine {
+        Stdout(String),
+        Stderr(String),
+    }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// How the output of a solution bin is handled.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum OutputMode {
+        /// Print output as it arrives.
+        Forward,
+        /// Collect output, so that it can be printed once the bin has exited.
+        Buffer,
+        Discard,
+    }
+
+    /// Build the registry bin, which contains all days. Returns its path, or `None` if the build failed.
+    pub fn build_registry(is_release: bool) -> Result<Option<PathBuf>, Error> {
+        build(REGISTRY_BIN, profile_args(is_release))
+    }
+
+    /// Run the solution for a given day and collect the reports of its parts.
+    /// With `registry`, the day is run through the registry bin at that path, see [`build_registry`].
+    /// Otherwise, the day's own bin is built and run.
+    pub fn run_solution(
+        day: Day,
+        bench: Option<BenchConfig>,
+        is_release: bool,
+        timeout: Option<Duration>,
+        registry: Option<&Path>,
+        output_mode: OutputMode,
+    ) -> Result<SolutionOutput, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            return Ok(SolutionOutput::default());
+        }
+
+        let mut args = vec![];
+
+        let executable = if let Some(registry) = registry {
+            args.push(day.to_string());
+            registry.to_path_buf()
+        } else {
+            match build(&day.bin_name(), profile_args(is_release))? {
+                Some(executable) => executable,
+                None => return Ok(SolutionOutput::default()),
+            }
+        };
+
+        if let Some(bench) = bench {
+            // mirror `--time` flag and bench settings to child invocations.
+            args.push("--time".to_string());
+            args.extend(bench.to_args());
+        }
+
+        execute(day, &executable, args, timeout, output_mode)
+    }
+
+    /// Run the solution bin for a given day once with heap profiling enabled and collect the reports of its parts.
+    /// Uses a dedicated build profile, so that the profiling allocator does not leak into regular builds.
+    pub fn run_heap_profile(day: Day, timeout: Option<Duration>) -> Result<SolutionOutput, Error> {
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            return Ok(SolutionOutput::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let Some(executable) = build(
+            &day.bin_name(),
+            &["--profile", "dhat", "--features", "dhat-heap"],
+        )?
+        else {
+            return Ok(SolutionOutput::default());
+        };
 
+        // NOTE: output is discarded, answers and timings were already printed by the regular run.
+        execute(
+            day,
+            &executable,
+            vec!["--heap".to_string()],
+            timeout,
+            OutputMode::Discard,
+        )
+    }
+
+    fn profile_args(is_release: bool) -> &'static [&'static str] {
         if is_release {
-            args.push("--release");
+            &["--release"]
+        } else {
+            &[]
+        }
+    }
+
+    /// Build a bin upfront so that compile time does not count towards the day timeout.
+    /// Returns the path of the executable, or `None` if the build failed.
+    fn build(bin: &str, profile_args: &[&str]) -> Result<Option<PathBuf>, Error> {
+        // NOTE: the path is taken from cargo's own messages, so that `build.target-dir` and custom profiles are honored.
+        let output = Command::new("cargo")
+            .args([
+                "build",
+                "--quiet",
+                "--message-format=json-render-diagnostics",
+            ])
+            .args(["--bin", bin])
+            .args(profile_args)
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        if !output.status.success() {
+            return Ok(None);
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let executable = executable_from_messages(&String::from_utf8_lossy(&output.stdout), bin);
+
+        executable
+            .ok_or_else(|| Error::Build(format!("cargo did not report an executable for `{bin}`.")))
+            .map(Some)
+    }
+
+    /// Read the path of a bin from the JSON messages of `cargo build --message-format=json`.
+    fn executable_from_messages(messages: &str, bin: &str) -> Option<PathBuf> {
+        messages
+            .lines()
+            .filter_map(|line| line.parse::<JsonValue>().ok())
+            .find_map(|message| {
+                let message: &HashMap<String, JsonValue> = message.get()?;
+                let target: &HashMap<String, JsonValue> = message.get("target")?.get()?;
+                let name: &String = target.get("name")?.get()?;
+                let executable: &String = message.get("executable")?.get()?;
+                (name == bin).then(|| PathBuf::from(executable))
+            })
+    }
+
+    fn execute(
+        day: Day,
+        executable: &Path,
+        mut args: Vec<String>,
+        timeout: Option<Duration>,
+        output_mode: OutputMode,
+    ) -> Result<SolutionOutput, Error> {
+        if let Some(timeout) = timeout {
+            args.push("--timeout".to_string());
+            args.push(timeout.as_secs().to_string());
         }
 
+        let report_path = env::temp_dir().join(format!(
+            "aoc-report-{}-{}.jsonl",
+            process::id(),
+            day.bin_name()
+        ));
+        let _ = fs::remove_file(&report_path);
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward or buffer output, results are read from the report file afterwards.
 
-        let mut cmd = Command::new("cargo")
+        // NOTE: the bin is invoked directly (not via `cargo run`) so that killing it stops the solution.
+        let mut cmd = Command::new(executable)
             .args(&args)
+            .env(REPORT_PATH_ENV, &report_path)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -108,85 +473,121 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let output = Arc::new(Mutex::new(vec![]));
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        let handle_line = move |line: OutputLine, output: &Mutex<Vec<OutputLine>>| match output_mode
+        {
+            OutputMode::Forward => match line {
+                OutputLine::Stdout(line) => println!("{line}"),
+                OutputLine::Stderr(line) => eprintln!("{line}"),
+            },
+            OutputMode::Buffer => output.lock().unwrap().push(line),
+            OutputMode::Discard => {}
+        };
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let stderr_thread = {
+            let output = Arc::clone(&output);
+            thread::spawn(move || {
+                stderr.lines().for_each(|line| {
+                    handle_line(OutputLine::Stderr(line.unwrap()), &output);
+                });
+            })
+        };
+
+        let stdout_thread = {
+            let output = Arc::clone(&output);
+            thread::spawn(move || {
+                stdout.lines().for_each(|line| {
+                    handle_line(OutputLine::Stdout(line.unwrap()), &output);
+                });
+            })
+        };
+
+        let mut killed = false;
+
+        let status = match timeout.map(|timeout| Instant::now() + day_timeout(timeout)) {
+            Some(deadline) => loop {
+                if let Some(status) = cmd.try_wait()? {
+                    break status;
+                }
+                if Instant::now() >= deadline {
+                    cmd.kill()?;
+                    killed = true;
+                    break cmd.wait()?;
+                }
+                thread::sleep(Duration::from_millis(10));
+            },
+            None => cmd.wait()?,
+        };
+
+        stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
+
+        let reports = report::read(&report_path).map_err(Error::Report);
+        let _ = fs::remove_file(&report_path);
+        let reports = reports?;
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let output = std::mem::take(&mut *output.lock().unwrap());
 
-        Ok(output)
+        let timed_out = killed || reports.iter().any(|r| r.timed_out);
+
+        Ok(SolutionOutput {
+            timed_out,
+            success: status.success(),
+            panicked: !status.success() && !timed_out,
+            reports,
+            output,
+        })
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    /// Collect the timings of all successful parts of a day.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn timing_from_reports(reports: &[PartReport], day: Day) -> super::Timing {
         let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+            timed_out: reports.iter().any(|r| r.timed_out),
+            failed: reports.iter().any(|r| r.error.is_some()),
+            ..super::Timing::new(day)
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        reports.iter().filter(|r| r.success).for_each(|r| {
+            let timing_str = Some(format!("{:.1?}", r.stats.median));
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            match r.part {
+                0 => {
+                    timings.parse = timing_str;
+                    timings.parse_stats = Some(r.stats);
+                    timings.parse_counters = r.counters;
+                }
+                1 => {
+                    timings.part_1 = timing_str;
+                    timings.part_1_stats = Some(r.stats);
+                    timings.part_1_counters = r.counters;
+                }
+                2 => {
+                    timings.part_2 = timing_str;
+                    timings.part_2_stats = Some(r.stats);
+                    timings.part_2_counters = r.counters;
                 }
+                _ => return,
+            }
 
-                timings.total_nanos += nanos;
-            });
+            timings.total_nanos += r.stats.median.as_nanos() as f64;
+        });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// Add the heap stats of profiled parts to the timing of a day.
+    pub fn apply_heap_reports(timing: &mut super::Timing, reports: &[PartReport]) {
+        reports
+            .iter()
+            .filter(|r| r.success)
+            .for_each(|r| match r.part {
+                0 => timing.parse_heap = r.heap,
+                1 => timing.part_1_heap = r.heap,
+                2 => timing.part_2_heap = r.heap,
+                _ => {}
+            });
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,53 +606,245 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{apply_heap_reports, executable_from_messages, timing_from_reports};
+        use std::{path::PathBuf, time::Duration};
 
-        use crate::day;
+        use crate::{
+            day,
+            template::{
+                report::PartReport,
+                runner::{BenchStats, HeapStats},
+            },
+        };
+
+        fn report(part: u8, answer: Option<&str>, nanos: u64, samples: u128) -> PartReport {
+            PartReport {
+                part,
+                success: answer.is_some(),
+                timed_out: false,
+                answer: answer.map(str::to_string),
+                error: None,
+                stats: BenchStats {
+                    samples,
+                    ..BenchStats::single(Duration::from_nanos(nanos))
+                },
+                heap: None,
+                counters: None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    report(1, Some("0"), 74, 100_000),
+                    report(2, Some("10"), 74_130_000, 99_999),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_2_stats.unwrap().samples, 99_999);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_with_patterns_in_answers() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    report(1, Some("@ @ @ ( ) ms (2s @ 5 samples)"), 2_000_000_000, 5),
+                    report(2, Some("10s"), 100_000_000, 1),
                 ],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
+        fn collects_missing_parts() {
+            let res = timing_from_reports(
+                &[report(1, None, 1_000, 1), report(2, None, 1_000, 1)],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.part_1_stats.is_none(), true);
+        }
+
+        #[test]
+        fn collects_parse_times() {
+            let res = timing_from_reports(
+                &[
+                    PartReport {
+                        part: 0,
+                        success: true,
+                        timed_out: false,
+                        answer: None,
+                        error: None,
+                        stats: BenchStats::single(Duration::from_micros(20)),
+                        heap: None,
+                        counters: None,
+                    },
+                    report(1, Some("1"), 10_000, 1),
+                    report(2, Some("2"), 30_000, 1),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 60000_f64);
+            assert_eq!(res.parse.unwrap(), "20.0µs");
+            assert_eq!(res.part_1.unwrap(), "10.0µs");
+            assert_eq!(res.parse_stats.unwrap().median, Duration::from_micros(20));
+        }
+
+        #[test]
+        fn collects_timed_out_parts() {
+            let res = timing_from_reports(
+                &[
+                    report(1, Some("1"), 10_000, 1),
+                    PartReport {
+                        part: 2,
+                        success: false,
+                        timed_out: true,
+                        answer: None,
+                        error: None,
+                        stats: BenchStats::single(Duration::from_secs(10)),
+                        heap: None,
+                        counters: None,
+                    },
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 10000_f64);
+            assert_eq!(res.timed_out, true);
+            assert_eq!(res.part_2.is_none(), true);
+        }
+
+        #[test]
+        fn applies_heap_reports() {
+            let heap = HeapStats {
+                peak_bytes: 4096,
+                total_allocations: 12,
+                total_bytes: 8192,
+            };
+            let reports = [
+                PartReport {
+                    heap: Some(heap),
+                    ..report(1, Some("1"), 10_000, 1)
+                },
+                PartReport {
+                    heap: Some(heap),
+                    ..report(2, None, 10_000, 1)
+                },
+            ];
+
+            let mut timing = timing_from_reports(&[report(1, Some("1"), 10_000, 1)], day!(1));
+            apply_heap_reports(&mut timing, &reports);
+
+            assert_eq!(timing.part_1_heap, Some(heap));
+            assert_eq!(timing.part_2_heap, None);
+            assert_eq!(timing.parse_heap, None);
+        }
+
+        #[test]
+        fn reads_executable_from_build_messages() {
+            let messages = r#"{"reason":"compiler-artifact","target":{"kind":["lib"],"name":"advent_of_code"},"executable":null}
+{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"05"},"executable":"/tmp/build/release/05"}
+{"reason":"build-finished","success":true}"#;
+
+            assert_eq!(
+                executable_from_messages(messages, "05"),
+                Some(PathBuf::from("/tmp/build/release/05"))
+            );
+            assert_eq!(executable_from_messages(messages, "advent_of_code"), None);
+            assert_eq!(executable_from_messages(messages, "06"), None);
         }
     }
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{child_commands::SolutionOutput, run_parallel, RunState};
+    use crate::{
+        day,
+        template::{report::PartReport, runner::BenchStats},
+    };
+    use std::{sync::Mutex, thread, time::Duration};
+
+    fn run_state() -> RunState {
+        RunState {
+            timings: vec![],
+            timed_out_days: vec![],
+            failed_days: vec![],
+            need_space: false,
+        }
+    }
+
+    #[test]
+    fn fails_days_that_panicked() {
+        let parse = PartReport {
+            part: 0,
+            success: true,
+            timed_out: false,
+            answer: None,
+            error: None,
+            stats: BenchStats::single(Duration::from_micros(3)),
+            heap: None,
+            counters: None,
+        };
+        let output = SolutionOutput {
+            reports: vec![parse],
+            panicked: true,
+            ..SolutionOutput::default()
+        };
+
+        let mut state = run_state();
+        state.handle_output(day!(1), &output, None, None);
+
+        assert_eq!(state.failed_days, vec![day!(1)]);
+        assert_eq!(state.timings[0].failed, true);
+
+        let early_panic = SolutionOutput {
+            panicked: true,
+            ..SolutionOutput::default()
+        };
+        state.handle_output(day!(2), &early_panic, None, None);
+        state.handle_output(day!(3), &SolutionOutput::default(), None, None);
+
+        assert_eq!(state.failed_days, vec![day!(1), day!(2)]);
+    }
+
+    #[test]
+    fn handles_days_in_order() {
+        let days = [day!(1), day!(2), day!(3), day!(4)];
+        let finished = Mutex::new(vec![]);
+        let mut handled = vec![];
+
+        // NOTE: earlier days take longer, so that they finish last.
+        run_parallel(
+            &days,
+            4,
+            |day| {
+                thread::sleep(Duration::from_millis(40 * u64::from(5 - day.into_inner())));
+                finished.lock().unwrap().push(day);
+                format!("output of day {day}")
+            },
+            |day, output| handled.push((day, output)),
+        );
+
+        assert_eq!(
+            *finished.lock().unwrap(),
+            days.iter().rev().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            handled,
+            days.iter()
+                .map(|day| (*day, format!("output of day {day}")))
+                .collect::<Vec<_>>()
+        );
+    }
+}
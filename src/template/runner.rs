@@ -2,101 +2,642 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
+use crate::template::answers::{self, AnswerStatus, Answers};
+use crate::template::report::{self, PartReport};
+use crate::template::submissions::{self, Ledger, Submission, Verdict};
 use crate::template::ANSI_BOLD;
 use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{input, ocr, perf, trace, viz};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Exit code of a solution binary that was stopped by its watchdog.
+pub const TIMEOUT_EXIT_CODE: i32 = 124;
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// The return type of a solution part or parse stage: `Option<T>` or `Result<T, E>` with an error `E` that implements [`Display`].
+pub trait PartResult {
+    type Value;
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    /// Convert into the value, or an error. The error is `None` if the part did not produce an answer without reporting why.
+    fn into_result(self) -> Result<Self::Value, Option<String>>;
+}
+
+impl<T> PartResult for Option<T> {
+    type Value = T;
+
+    fn into_result(self) -> Result<T, Option<String>> {
+        self.ok_or(None)
+    }
+}
+
+impl<T, E: Display> PartResult for Result<T, E> {
+    type Value = T;
+
+    fn into_result(self) -> Result<T, Option<String>> {
+        self.map_err(|e| Some(e.to_string()))
+    }
+}
+
+pub fn run_part<I: Copy, R: PartResult>(func: impl Fn(I) -> R, input: I, day: Day, part: u8)
+where
+    R::Value: Display,
+{
+    let part_str = part_label(part);
+
+    viz::begin(day, part);
+
+    let (result, measurement) = run_timed(
+        |input| func(input).into_result(),
+        input,
+        part,
+        |result| {
+            print_result(result, &part_str, "");
+        },
+    );
+
+    // NOTE: letters drawn as ASCII art are checked, stored and submitted as the decoded string.
+    let answer = result.as_ref().ok().map(|result| {
+        let answer = result.to_string();
+        ocr::decode(&answer).unwrap_or(answer)
+    });
+
+    // NOTE: answers for another input are checked against the expected answers of the example, if any.
+    let status_str = match &answer {
+        Some(answer) if input::is_custom() => match input::expected(part) {
+            Some(expected) if expected == answer => format_answer_status(&AnswerStatus::Correct),
+            Some(expected) => format_answer_status(&AnswerStatus::Incorrect {
+                expected: expected.to_string(),
+            }),
+            None => String::new(),
+        },
+        Some(answer) => format_answer_status(&Answers::read_from_file(day).check(part, answer)),
+        None => String::new(),
+    };
+
+    print_result(&result, &part_str, &format!("{status_str}{measurement}"));
+
+    emit_report(PartReport {
+        part,
+        success: result.is_ok(),
+        timed_out: false,
+        answer: answer.clone(),
+        error: result.as_ref().err().cloned().flatten(),
+        stats: measurement.stats,
+        heap: measurement.heap,
+        counters: measurement.counters,
+    });
+
+    // NOTE: errors are never submitted.
+    if let Some(answer) = answer {
+        if submit_result(&answer, day, part) == Some(Verdict::Correct) {
+            match answers::store_answer(day, part, &answer) {
+                Ok(path) => println!("Stored answer in \"{path}\"."),
+                Err(e) => eprintln!("Failed to store answer: {e}"),
+            }
+        }
+    }
+}
+
+fn format_answer_status(status: &AnswerStatus) -> String {
+    match status {
+        AnswerStatus::Correct => " ✔".into(),
+        AnswerStatus::Incorrect { expected } => format!(" ✖ (expected {expected})"),
+        AnswerStatus::Unknown => " ?".into(),
+    }
+}
+
+/// Run the parse stage of a solution. It is timed separately and its result is handed to every part.
+pub fn run_parse<R: PartResult>(func: impl Fn(&str) -> R, input: &str) -> Option<R::Value> {
+    let part_str = part_label(0);
+    let success_str = |result: &Result<R::Value, Option<String>>| {
+        result.as_ref().map(|_| "✔").map_err(Clone::clone)
+    };
+
+    let (result, measurement) = run_timed(
+        |input| func(input).into_result(),
+        input,
+        0,
+        |result| {
+            print_result(&success_str(result), &part_str, "");
+        },
+    );
+
+    print_result(&success_str(&result), &part_str, &measurement.to_string());
+
+    emit_report(PartReport {
+        part: 0,
+        success: result.is_ok(),
+        timed_out: false,
+        answer: None,
+        error: result.as_ref().err().cloned().flatten(),
+        stats: measurement.stats,
+        heap: measurement.heap,
+        counters: measurement.counters,
+    });
+
+    result.ok()
+}
+
+/// Report a part that could not be run because the parse stage failed.
+pub fn skip_part(part: u8) {
+    print_result(&Err::<&str, _>(None), &part_label(part), " ");
+
+    emit_report(PartReport {
+        part,
+        success: false,
+        timed_out: false,
+        answer: None,
+        error: None,
+        stats: BenchStats::single(Duration::ZERO),
+        heap: None,
+        counters: None,
+    });
+}
+
+fn emit_report(report: PartReport) {
+    if let Err(e) = report::emit(&report) {
+        eprintln!("failed to write report: {e}");
+    }
+}
+
+fn part_label(part: u8) -> String {
+    if part == 0 {
+        "Parse".to_string()
+    } else {
+        format!("Part {part}")
+    }
+}
+
+/// Per-part timeout of `all`, `time` and `verify` if `--timeout` is not passed.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Read the `--timeout` argument (in seconds) of the current process.
+pub fn timeout_from_args() -> Option<Duration> {
+    let args: Vec<String> = env::args().collect();
+    arg_value(&args, "--timeout").map(Duration::from_secs)
+}
+
+/// Start a watchdog that stops the process if a part does not finish within `timeout`.
+/// The watchdog is disarmed when the returned sender is dropped.
+fn watchdog(part: u8, timeout: Duration) -> Sender<()> {
+    let (tx, rx) = mpsc::channel::<()>();
+
+    thread::spawn(move || {
+        if rx.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout) {
+            print!("\r");
+            println!("{}: timed out after {timeout:.1?}", part_label(part));
+
+            emit_report(PartReport {
+                part,
+                success: false,
+                timed_out: true,
+                answer: None,
+                error: None,
+                stats: BenchStats::single(timeout),
+                heap: None,
+                counters: None,
+            });
+
+            process::exit(TIMEOUT_EXIT_CODE);
+        }
+    });
+
+    tx
+}
+
+/// Settings that control how a solution part is benched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Fixed number of samples to collect. Derived from `duration` if not set.
+    pub samples: Option<u128>,
+    /// Approximate time budget of a benchmark, excluding warmup.
+    pub duration: Duration,
+    /// Collect heap statistics. These are gathered by `run_multi` in a separate, unbenched pass
+    /// because the profiling allocator would distort timings.
+    pub heap: bool,
+    /// Read hardware performance counters (Linux only).
+    pub perf: bool,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            samples: None,
+            duration: Duration::from_secs(1),
+            heap: false,
+            perf: false,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Read the `--samples`, `--bench-duration` (in milliseconds), `--heap` and `--perf` arguments of the current process.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let default = Self::default();
+
+        Self {
+            samples: arg_value(&args, "--samples"),
+            duration: arg_value(&args, "--bench-duration")
+                .map_or(default.duration, Duration::from_millis),
+            heap: args.iter().any(|x| x == "--heap"),
+            perf: args.iter().any(|x| x == "--perf"),
+        }
+    }
+
+    /// Mirror the config to the arguments of a child invocation. Does not include `heap`, see [`BenchConfig::heap`].
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--bench-duration".to_string(),
+            self.duration.as_millis().to_string(),
+        ];
+
+        if let Some(samples) = self.samples {
+            args.push("--samples".to_string());
+            args.push(samples.to_string());
+        }
+
+        if self.perf {
+            args.push("--perf".to_string());
+        }
+
+        args
+    }
+}
+
+fn arg_value<T: FromStr>(args: &[String], flag: &str) -> Option<T> {
+    let index = args.iter().position(|x| x == flag)? + 1;
+    args.get(index)?.parse().ok()
+}
+
+/// Hardware event counts of a single run of a solution part, averaged over multiple runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PerfCounters {
+    pub instructions: u64,
+    pub cycles: u64,
+    pub cache_misses: u64,
+    pub branch_misses: u64,
+}
+
+impl Display for PerfCounters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} instructions, {} cycles, {} cache misses, {} branch misses",
+            format_count(self.instructions),
+            format_count(self.cycles),
+            format_count(self.cache_misses),
+            format_count(self.branch_misses)
+        )
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_count(count: u64) -> String {
+    match count {
+        0..1_000 => count.to_string(),
+        1_000..1_000_000 => format!("{:.1}k", count as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}M", count as f64 / 1e6),
+        _ => format!("{:.1}G", count as f64 / 1e9),
+    }
+}
+
+/// Maximum number of runs that hardware counters are averaged over.
+const PERF_RUNS: u128 = 100;
+
+static PERF_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// Count hardware events of `runs` runs of a solution part. Prints a warning once if counters are unavailable.
+fn count_events<I: Copy, T>(func: impl Fn(I) -> T, input: I, runs: u64) -> Option<PerfCounters> {
+    let _muted = trace::Muted::new();
+
+    let counters = perf::Counters::open().and_then(|mut counters| {
+        counters.measure(|| {
+            for _ in 0..runs {
+                black_box(func(black_box(input)));
+            }
+        })?;
+        counters.read(runs)
+    });
+
+    match counters {
+        Ok(counters) => Some(counters),
+        Err(e) => {
+            if !PERF_WARNED.swap(true, AtomicOrdering::Relaxed) {
+                eprintln!("\nHardware counters are unavailable: {e}");
+            }
+            None
+        }
+    }
+}
+
+/// Heap usage of a single run of a solution part, as recorded by dhat.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Bytes allocated at the peak of the run.
+    pub peak_bytes: u64,
+    pub total_allocations: u64,
+    pub total_bytes: u64,
+}
+
+impl Display for HeapStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak, {} allocs, {} total",
+            format_bytes(self.peak_bytes),
+            self.total_allocations,
+            format_bytes(self.total_bytes)
+        )
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Summary statistics of a benched solution part.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BenchStats {
+    pub samples: u128,
+    /// Duration of the first, cold run. Not part of the other statistics.
+    pub cold: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    /// Number of samples outside of the Tukey fences (1.5 × IQR).
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Statistics for a part that was only executed once.
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            samples: 1,
+            cold: duration,
+            min: duration,
+            median: duration,
+            mean: duration,
+            p95: duration,
+            stddev: Duration::ZERO,
+            outliers: 0,
+        }
+    }
+
+    /// Compute statistics from a non-empty set of samples.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(cold: Duration, samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let nanos: Vec<f64> = sorted.iter().map(|x| x.as_nanos() as f64).collect();
+        let len = nanos.len() as f64;
+
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (len - 1.0)
+        } else {
+            0.0
+        };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (fence_low, fence_high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Self {
+            samples: sorted.len() as u128,
+            cold,
+            min: sorted[0],
+            median: nanos_to_duration(percentile(&nanos, 0.5)),
+            mean: nanos_to_duration(mean),
+            p95: nanos_to_duration(percentile(&nanos, 0.95)),
+            stddev: nanos_to_duration(variance.sqrt()),
+            outliers: nanos
+                .iter()
+                .filter(|&&x| x < fence_low || x > fence_high)
+                .count(),
+        }
     }
 }
 
+/// Linearly interpolated percentile of sorted values.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor())
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn nanos_to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched (approx. `--bench-duration` of execution time or 10 samples, whatever take longer.)
+///     The duration defaults to 1 second, `--samples` overrides the sample count.
+///
+/// With `--timeout`, a watchdog stops the process if the part (including benching) exceeds the timeout.
+/// Benching stops sampling early when the next sample would not finish in time.
+///
+/// With the `viz` feature, the frames emitted by the first run are written once it has been timed, see [`viz`].
+///
+/// With the `dhat-heap` feature, the first run is profiled. Passing `--heap` additionally returns its heap statistics.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    part: u8,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Measurement) {
+    let timeout = timeout_from_args();
+    let _watchdog = timeout.map(|timeout| watchdog(part, timeout));
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let config = BenchConfig::from_args();
+
     let timer = Instant::now();
-    let result = {
+    let (result, heap) = {
+        // NOTE: testing mode keeps dhat from writing a profile file for every part.
         #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+        let _profiler = if config.heap {
+            dhat::Profiler::builder().testing().build()
+        } else {
+            dhat::Profiler::new_heap()
+        };
+
+        let result = func(input);
 
-        func(input)
+        #[cfg(feature = "dhat-heap")]
+        let heap = config.heap.then(|| {
+            let stats = dhat::HeapStats::get();
+            HeapStats {
+                peak_bytes: stats.max_bytes as u64,
+                total_allocations: stats.total_blocks,
+                total_bytes: stats.total_bytes,
+            }
+        });
+        #[cfg(not(feature = "dhat-heap"))]
+        let heap = None;
+
+        (result, heap)
     };
     let base_time = timer.elapsed();
 
+    // NOTE: only the first run records frames, benching and counting events run without them.
+    // they are played back or written here, so that rendering does not count towards the time of the part.
+    viz::end();
+
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if std::env::args().any(|x| x == "--time") {
+        bench(&func, input, &base_time, &config, deadline)
+    } else {
+        BenchStats::single(base_time)
+    };
+
+    // NOTE: counters are read in a separate pass, so that the syscalls do not count towards the timings.
+    let runs = stats.samples.clamp(1, PERF_RUNS);
+    let has_time_left = deadline.is_none_or(|deadline| {
+        Instant::now() + stats.median * u32::try_from(runs).unwrap_or(u32::MAX) < deadline
+    });
+
+    let counters = if config.perf && has_time_left {
+        count_events(&func, input, u64::try_from(runs).unwrap_or(1))
     } else {
-        (base_time, 1)
+        None
     };
 
-    (result, run.0, run.1)
+    (
+        result,
+        Measurement {
+            stats,
+            heap,
+            counters,
+        },
+    )
+}
+
+/// Everything recorded for a single solution part.
+struct Measurement {
+    stats: BenchStats,
+    heap: Option<HeapStats>,
+    counters: Option<PerfCounters>,
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format_duration(&self.stats))?;
+
+        if let Some(counters) = self.counters {
+            write!(f, " [{counters}]")?;
+        }
+
+        Ok(())
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Bench a solution part. A tenth of the sample count is spent on warmup iterations which are not measured.
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+    deadline: Option<Instant>,
+) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    // NOTE: debug output is muted, it would flood the terminal and skew the timings.
+    let _muted = trace::Muted::new();
+
+    let bench_iterations = config.samples.unwrap_or_else(|| {
+        (config.duration.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000)
+    });
+
+    let has_time_left =
+        || deadline.is_none_or(|deadline| Instant::now() + *base_time * 2 < deadline);
+
+    for _ in 0..cmp::max(bench_iterations / 10, 1) {
+        if !has_time_left() {
+            break;
+        }
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
-    for _ in 0..bench_iterations {
+    for _ in 0..cmp::max(bench_iterations, 1) {
+        if !has_time_left() {
+            break;
+        }
         let timer = Instant::now();
         black_box(func(black_box(input)));
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
-}
+    if timers.is_empty() {
+        return BenchStats::single(*base_time);
+    }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+    BenchStats::from_samples(*base_time, &timers)
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(stats: &BenchStats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.median)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} @ {} samples) [min {:.1?}, mean {:.1?}, p95 {:.1?}, σ {:.1?}, cold {:.1?}, {} outliers]",
+            stats.median,
+            stats.samples,
+            stats.min,
+            stats.mean,
+            stats.p95,
+            stats.stddev,
+            stats.cold,
+            stats.outliers
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result<T: Display>(result: &Result<T, Option<String>>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(result) => {
             if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+                let decoded = ocr::decode(&result.to_string())
+                    .map(|text| format!(" {ANSI_BOLD}{text}{ANSI_RESET}"))
+                    .unwrap_or_default();
+                let str = format!("{part}: ▼{decoded} {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -114,7 +655,16 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Err(Some(error)) => {
+            let str = format!("{part}: ✖ {error}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}{duration_str}");
+            }
+        }
+        Err(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -128,11 +678,11 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  3. the answer passes the checks of the submission ledger, or `--force` is passed.
+///  4. the cooldown AoC asked for has expired. With `--wait`, the cooldown is waited out instead.
+///
+/// Every submission is recorded in the ledger, returns the verdict of AoC.
+fn submit_result<T: Display>(result: T, day: Day, part: u8) -> Option<Verdict> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,11 +705,213 @@ fn submit_result<T: Display>(
         return None;
     }
 
+    if input::is_custom() {
+        eprintln!("Not submitting, the answer is not for the puzzle input.");
+        return None;
+    }
+
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = result.to_string();
+
+    let ledger = Ledger::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read submission ledger: {e}");
+        process::exit(1);
+    });
+
+    if let Err(refusal) = ledger.check(day, part, &answer) {
+        if !refusal.is_forceable() {
+            eprintln!("Refusing to submit: {refusal}.");
+            return None;
+        }
+        if !args.contains(&"--force".into()) {
+            eprintln!("Refusing to submit {answer}: {refusal}. Pass --force to submit anyway.");
+            return None;
+        }
+        eprintln!("Submitting {answer} despite: {refusal}.");
+    }
+
+    let wait = args.contains(&"--wait".into());
+    let mut cooldown = submissions::read_cooldown(day);
+
+    loop {
+        if let Some(remaining) = cooldown {
+            if !wait {
+                eprintln!(
+                    "AoC asked to wait before submitting again, {} left. Pass --wait to submit once the cooldown expires.",
+                    format_wait(remaining)
+                );
+                return None;
+            }
+            wait_for_cooldown(remaining);
+        }
+
+        println!("Submitting result via aoc-cli...");
+
+        let output = match aoc_cli::submit(day, part, &answer) {
+            Ok(output) | Err(aoc_cli::AocCommandError::BadExitStatus(output)) => output,
+            Err(e) => {
+                eprintln!("Failed to submit: {e}");
+                return None;
+            }
+        };
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let verdict = Verdict::from_output(&stdout);
+        cooldown = submissions::parse_cooldown(&stdout);
+
+        if let Some(cooldown) = cooldown {
+            if let Err(e) = submissions::store_cooldown(day, cooldown) {
+                eprintln!("Failed to store cooldown: {e}");
+            }
+        }
+
+        if let Err(e) = Ledger::append(&Submission::new(day, part, &answer, verdict)) {
+            eprintln!("Failed to record submission: {e}");
+        }
+
+        // NOTE: only resubmit if the rate limit told us how long to wait, to never hammer AoC.
+        if !(wait && verdict == Verdict::RateLimited && cooldown.is_some()) {
+            return Some(verdict);
+        }
+    }
+}
+
+/// Count down a cooldown on a single line.
+fn wait_for_cooldown(remaining: Duration) {
+    let deadline = Instant::now() + remaining;
+
+    loop {
+        let left = deadline.saturating_duration_since(Instant::now());
+
+        if left.is_zero() {
+            break;
+        }
+
+        print!("\rWaiting for cooldown, {} left...  ", format_wait(left));
+        let _ = stdout().flush();
+        thread::sleep(cmp::min(left, Duration::from_secs(1)));
+    }
+
+    println!("\rCooldown expired.{}", " ".repeat(24));
+}
+
+/// Format a wait time like AoC does, e.g. `1m 23s`.
+fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+
+    match (hours, minutes) {
+        (0, 0) => format!("{seconds}s"),
+        (0, _) => format!("{minutes}m {seconds}s"),
+        _ => format!("{hours}h {minutes}m {seconds}s"),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_wait, BenchConfig, BenchStats, HeapStats, PartResult, PerfCounters};
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_bench_stats() {
+        let stats =
+            BenchStats::from_samples(Duration::from_nanos(500), &nanos(&[40, 10, 30, 20, 50]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.cold, Duration::from_nanos(500));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.stddev, Duration::from_nanos(16));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn detects_outliers() {
+        let stats = BenchStats::from_samples(
+            Duration::ZERO,
+            &nanos(&[10, 11, 10, 12, 11, 10, 11, 500, 12, 1]),
+        );
+        assert_eq!(stats.outliers, 2);
+        assert_eq!(stats.median, Duration::from_nanos(11));
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = BenchStats::from_samples(Duration::ZERO, &nanos(&[42]));
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn formats_heap_stats() {
+        let stats = HeapStats {
+            peak_bytes: 1536,
+            total_allocations: 42,
+            total_bytes: 3 * 1024 * 1024,
+        };
+        assert_eq!(stats.to_string(), "1.5 KiB peak, 42 allocs, 3.0 MiB total");
+
+        let stats = HeapStats {
+            peak_bytes: 0,
+            total_allocations: 0,
+            total_bytes: 100,
+        };
+        assert_eq!(stats.to_string(), "0 B peak, 0 allocs, 100 B total");
+    }
+
+    #[test]
+    fn mirrors_bench_config_to_args() {
+        let config = BenchConfig {
+            samples: Some(100),
+            duration: Duration::from_millis(250),
+            heap: true,
+            perf: true,
+        };
+        assert_eq!(
+            config.to_args(),
+            ["--bench-duration", "250", "--samples", "100", "--perf"]
+        );
+    }
+
+    #[test]
+    fn formats_perf_counters() {
+        let counters = PerfCounters {
+            instructions: 1_234_567,
+            cycles: 45_600,
+            cache_misses: 999,
+            branch_misses: 2_500_000_000,
+        };
+        assert_eq!(
+            counters.to_string(),
+            "1.2M instructions, 45.6k cycles, 999 cache misses, 2.5G branch misses"
+        );
+    }
+
+    #[test]
+    fn formats_wait_times() {
+        assert_eq!(format_wait(Duration::from_secs(34)), "34s");
+        assert_eq!(format_wait(Duration::from_millis(82_100)), "1m 23s");
+        assert_eq!(format_wait(Duration::from_secs(3_723)), "1h 2m 3s");
+    }
+
+    #[test]
+    fn converts_part_results() {
+        assert_eq!(Some(143).into_result(), Ok(143));
+        assert_eq!(None::<u32>.into_result(), Err(None));
+        assert_eq!(Ok::<_, String>(143).into_result(), Ok(143));
+        assert_eq!(
+            "x".parse::<u32>().into_result(),
+            Err(Some("invalid digit found in string".into()))
+        );
+    }
 }
@@ -0,0 +1,505 @@
+/// Module that keeps a ledger of every submitted answer and the verdict AoC gave for it.
+/// The ledger is used to refuse submissions that are known to be wrong before they reach aoc-cli.
+/// It also remembers until when AoC asked to wait before submitting again.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+static LEDGER_FILE_PATH: &str = "./data/submissions.jsonl";
+static COOLDOWNS_FILE_PATH: &str = "./data/cooldowns.json";
+
+/// Verdict of AoC for a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    /// The response of AoC could not be interpreted.
+    Unknown,
+}
+
+impl Verdict {
+    /// Read the verdict from the output of `aoc submit`.
+    pub fn from_output(output: &str) -> Self {
+        if output.contains("That's the right answer") {
+            Verdict::Correct
+        } else if output.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if output.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if output.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if output.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn is_rejection(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate_limited",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str().replace('_', " "))
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::RateLimited,
+            Verdict::Unknown,
+        ]
+        .into_iter()
+        .find(|v| v.as_str() == s)
+        .ok_or(format!("unknown verdict `{s}`."))
+    }
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Submission {
+    pub fn new(day: Day, part: u8, answer: &str, verdict: Verdict) -> Self {
+        Self {
+            timestamp: now(),
+            day,
+            part,
+            answer: answer.trim().to_string(),
+            verdict,
+        }
+    }
+}
+
+/// Reason to refuse a submission.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyAccepted {
+        answer: String,
+    },
+    AlreadyRejected {
+        verdict: Verdict,
+    },
+    /// The answer is at least as high as an answer that was too high.
+    AboveBound {
+        bound: i128,
+    },
+    /// The answer is at most as low as an answer that was too low.
+    BelowBound {
+        bound: i128,
+    },
+    /// The answer is empty or 0.
+    Implausible,
+    /// The answer spans multiple lines, e.g. letters drawn as ASCII art that could not be decoded.
+    Undecoded,
+}
+
+impl Refusal {
+    /// Whether `--force` submits the answer anyway. An answer of multiple lines can never be submitted.
+    pub fn is_forceable(&self) -> bool {
+        *self != Refusal::Undecoded
+    }
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyAccepted { answer } => {
+                write!(f, "this part was already solved with {answer}")
+            }
+            Refusal::AlreadyRejected { verdict } => {
+                write!(
+                    f,
+                    "this answer was already submitted and rejected ({verdict})"
+                )
+            }
+            Refusal::AboveBound { bound } => write!(f, "{bound} was already too high"),
+            Refusal::BelowBound { bound } => write!(f, "{bound} was already too low"),
+            Refusal::Implausible => write!(f, "this answer looks implausible"),
+            Refusal::Undecoded => write!(
+                f,
+                "this answer spans multiple lines and could not be decoded to letters"
+            ),
+        }
+    }
+}
+
+/// All submissions, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    pub submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Read the ledger from disk. If not present, returns an empty ledger.
+    pub fn read_from_file() -> Result<Self, String> {
+        let Ok(content) = fs::read_to_string(LEDGER_FILE_PATH) else {
+            return Ok(Self::default());
+        };
+
+        Self::parse(&content)
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let submissions = content
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| {
+                let json = JsonValue::from_str(l).or(Err("submission is not valid JSON."))?;
+                Submission::try_from(&json)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { submissions })
+    }
+
+    /// Append a submission to the ledger on disk.
+    pub fn append(submission: &Submission) -> Result<(), io::Error> {
+        let line = JsonValue::from(submission)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(LEDGER_FILE_PATH)?;
+
+        writeln!(file, "{line}")
+    }
+
+    /// Check an answer against previous submissions of the same part before submitting it.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+        let answer = answer.trim();
+        let value = answer.parse::<i128>().ok();
+
+        if answer.contains('\n') {
+            return Err(Refusal::Undecoded);
+        }
+
+        // NOTE: negative answers are legitimate, only an empty answer or 0 is refused.
+        if answer.is_empty() || value == Some(0) {
+            return Err(Refusal::Implausible);
+        }
+
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part);
+
+        let mut upper: Option<i128> = None;
+        let mut lower: Option<i128> = None;
+
+        for submission in previous {
+            if submission.verdict == Verdict::Correct {
+                return Err(Refusal::AlreadyAccepted {
+                    answer: submission.answer.clone(),
+                });
+            }
+
+            if submission.answer == answer && submission.verdict.is_rejection() {
+                return Err(Refusal::AlreadyRejected {
+                    verdict: submission.verdict,
+                });
+            }
+
+            let Ok(submitted) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+
+            match submission.verdict {
+                Verdict::TooHigh => upper = Some(upper.map_or(submitted, |u| u.min(submitted))),
+                Verdict::TooLow => lower = Some(lower.map_or(submitted, |l| l.max(submitted))),
+                _ => {}
+            }
+        }
+
+        match (value, upper, lower) {
+            (Some(v), Some(bound), _) if v >= bound => Err(Refusal::AboveBound { bound }),
+            (Some(v), _, Some(bound)) if v <= bound => Err(Refusal::BelowBound { bound }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Read how long AoC asks to wait before the next submission from the output of `aoc submit`.
+/// Handles both the rate limit ("You have 1m 23s left to wait") and the penalty for wrong answers
+/// ("please wait 5 minutes before trying again").
+pub fn parse_cooldown(output: &str) -> Option<Duration> {
+    if let Some((_, rest)) = output.split_once("You have ") {
+        let (left, _) = rest.split_once(" left to wait")?;
+
+        return left
+            .split_whitespace()
+            .map(|part| {
+                let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+                let value = value.parse::<u64>().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let output = output.to_lowercase();
+    let (_, rest) = output.split_once("please wait ")?;
+    let (value, rest) = rest.split_once(' ')?;
+
+    if !rest.starts_with("minute") {
+        return None;
+    }
+
+    let minutes = match value {
+        "one" => 1,
+        value => value.parse::<u64>().ok()?,
+    };
+
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/// Get the remaining cooldown of a day, if any.
+pub fn read_cooldown(day: Day) -> Option<Duration> {
+    let deadline = *read_cooldowns().get(&day.to_string())?;
+    let now = now();
+    (deadline > now).then(|| Duration::from_secs(deadline - now))
+}
+
+/// Persist that a day can not be submitted again before `wait` has passed.
+#[allow(clippy::cast_precision_loss)]
+pub fn store_cooldown(day: Day, wait: Duration) -> Result<(), io::Error> {
+    let mut cooldowns = read_cooldowns();
+    cooldowns.insert(day.to_string(), now() + wait.as_secs());
+
+    let map: HashMap<String, JsonValue> = cooldowns
+        .into_iter()
+        .map(|(day, deadline)| (day, JsonValue::Number(deadline as f64)))
+        .collect();
+
+    let mut file = fs::File::create(COOLDOWNS_FILE_PATH)?;
+    JsonValue::Object(map).format_to(&mut file)
+}
+
+/// Read the cooldown deadlines of all days, in seconds since the unix epoch.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn read_cooldowns() -> HashMap<String, u64> {
+    let Ok(content) = fs::read_to_string(COOLDOWNS_FILE_PATH) else {
+        return HashMap::new();
+    };
+
+    JsonValue::from_str(&content)
+        .ok()
+        .and_then(|json| json.get::<HashMap<String, JsonValue>>().cloned())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(day, v)| Some((day, *v.get::<f64>()? as u64)))
+        .collect()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected submission.{key} to be a string."))
+        };
+
+        Ok(Submission {
+            timestamp: number("timestamp")? as u64,
+            day: Day::from_str(string("day")?)
+                .map_err(|_| "Expected submission.day to be a Day struct.")?,
+            part: number("part")? as u8,
+            answer: string("answer")?.clone(),
+            verdict: Verdict::from_str(string("verdict")?)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_cooldown, Ledger, Refusal, Submission, Verdict};
+    use crate::day;
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn ledger(submissions: &[(u8, &str, Verdict)]) -> Ledger {
+        Ledger {
+            submissions: submissions
+                .iter()
+                .map(|(part, answer, verdict)| Submission::new(day!(12), *part, answer, *verdict))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn reads_verdicts_from_output() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer.", Verdict::Correct),
+            ("That's not the right answer; your answer is too high.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            ("That's not the right answer. If you're stuck, ...", Verdict::Wrong),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 34s left to wait.", Verdict::RateLimited),
+            ("You don't seem to be solving the right level.", Verdict::Unknown),
+        ];
+
+        for (output, verdict) in cases {
+            assert_eq!(Verdict::from_output(output), verdict);
+        }
+    }
+
+    #[test]
+    fn round_trips_submissions() {
+        let submission = Submission::new(day!(12), 2, "1234", Verdict::TooLow);
+        let line = JsonValue::from(&submission).stringify().unwrap();
+        let ledger = Ledger::parse(&format!("{line}\n\n{line}")).unwrap();
+        assert_eq!(ledger.submissions, vec![submission.clone(), submission]);
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let ledger = ledger(&[(1, "42", Verdict::Wrong), (2, "7", Verdict::Correct)]);
+        assert_eq!(
+            ledger.check(day!(12), 1, "42"),
+            Err(Refusal::AlreadyRejected {
+                verdict: Verdict::Wrong
+            })
+        );
+        assert_eq!(
+            ledger.check(day!(12), 2, "8"),
+            Err(Refusal::AlreadyAccepted { answer: "7".into() })
+        );
+        assert_eq!(ledger.check(day!(12), 1, "43"), Ok(()));
+        assert_eq!(ledger.check(day!(13), 1, "42"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let ledger = ledger(&[
+            (1, "100", Verdict::TooHigh),
+            (1, "200", Verdict::TooHigh),
+            (1, "10", Verdict::TooLow),
+        ]);
+        assert_eq!(
+            ledger.check(day!(12), 1, "150"),
+            Err(Refusal::AboveBound { bound: 100 })
+        );
+        assert_eq!(
+            ledger.check(day!(12), 1, "5"),
+            Err(Refusal::BelowBound { bound: 10 })
+        );
+        assert_eq!(ledger.check(day!(12), 1, "50"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_implausible_answers() {
+        let ledger = Ledger::default();
+        assert_eq!(ledger.check(day!(12), 1, "0"), Err(Refusal::Implausible));
+        assert_eq!(ledger.check(day!(12), 1, " "), Err(Refusal::Implausible));
+        assert_eq!(ledger.check(day!(12), 1, "ABCD"), Ok(()));
+        assert_eq!(ledger.check(day!(12), 1, "-3"), Ok(()));
+
+        let refusal = ledger.check(day!(12), 1, "#..#\n####\n").unwrap_err();
+        assert_eq!(refusal, Refusal::Undecoded);
+        assert!(!refusal.is_forceable());
+    }
+
+    #[test]
+    fn parses_cooldowns() {
+        let cases = [
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 34s left to wait.", Some(34)),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait.", Some(83)),
+            ("That's not the right answer. If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.", Some(60)),
+            ("Because you have guessed incorrectly 5 times on this puzzle, please wait 5 minutes before trying again.", Some(300)),
+            ("That's the right answer! You are one gold star closer.", None),
+        ];
+
+        for (output, seconds) in cases {
+            assert_eq!(parse_cooldown(output), seconds.map(Duration::from_secs));
+        }
+    }
+}
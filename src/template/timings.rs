@@ -1,6 +1,13 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::Error,
+    str::FromStr,
+    time::Duration,
+};
 use tinyjson::JsonValue;
 
+use crate::template::runner::{BenchStats, HeapStats, PerfCounters};
 use crate::template::Day;
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
@@ -9,8 +16,21 @@ static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub parse_stats: Option<BenchStats>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    pub parse_heap: Option<HeapStats>,
+    pub part_1_heap: Option<HeapStats>,
+    pub part_2_heap: Option<HeapStats>,
+    pub parse_counters: Option<PerfCounters>,
+    pub part_1_counters: Option<PerfCounters>,
+    pub part_2_counters: Option<PerfCounters>,
+    pub timed_out: bool,
+    /// Whether a part returned an error.
+    pub failed: bool,
     pub total_nanos: f64,
 }
 
@@ -51,7 +71,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,10 +80,120 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    /// Whether both parts of a day are benched. The last day of an event only has a single part.
+    pub fn is_day_complete(&self, day: &Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == *day && t.part_1.is_some() && (t.part_2.is_some() || day.is_last()))
+    }
+
+    /// Compare every part of `baseline` of the days that were run.
+    /// A part that is missing from `self`, e.g. because it failed or timed out, is compared as well.
+    pub fn compare(&self, baseline: &Self, days: &HashSet<Day>) -> Vec<TimingDelta> {
+        let mut deltas = vec![];
+
+        for base in baseline.data.iter().filter(|t| days.contains(&t.day)) {
+            let timing = self.data.iter().find(|t| t.day == base.day);
+
+            for part in 0..=2 {
+                if let Some(baseline_nanos) = base.part_nanos(part) {
+                    deltas.push(TimingDelta {
+                        day: base.day,
+                        part,
+                        baseline_nanos,
+                        current_nanos: timing.and_then(|t| t.part_nanos(part)),
+                    });
+                }
+            }
+        }
+
+        deltas.sort_by_key(|d| (d.day, d.part));
+        deltas
+    }
+}
+
+impl Timing {
+    /// A day without any timings.
+    pub fn new(day: Day) -> Self {
+        Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            parse_stats: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_heap: None,
+            part_1_heap: None,
+            part_2_heap: None,
+            parse_counters: None,
+            part_1_counters: None,
+            part_2_counters: None,
+            timed_out: false,
+            failed: false,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// Duration of a part (`0` being the parse stage) in nanoseconds.
+    /// Uses the median if stats are present, the stored duration string otherwise.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        let (timing, stats) = match part {
+            0 => (&self.parse, &self.parse_stats),
+            1 => (&self.part_1, &self.part_1_stats),
+            2 => (&self.part_2, &self.part_2_stats),
+            _ => return None,
+        };
+
+        match stats {
+            Some(stats) => Some(stats.median.as_nanos() as f64),
+            None => timing.as_deref().and_then(parse_duration),
+        }
+    }
+}
+
+/// Change in duration of a single part between a baseline and a current run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingDelta {
+    pub day: Day,
+    /// The part, `0` being the parse stage.
+    pub part: u8,
+    pub baseline_nanos: f64,
+    /// `None` if the part has no timing in the current run, e.g. because it failed or timed out.
+    pub current_nanos: Option<f64>,
+}
+
+impl TimingDelta {
+    /// Relative change against the baseline in percent. Positive values are slowdowns.
+    /// `None` if the part is missing from the current run.
+    pub fn change_percent(&self) -> Option<f64> {
+        let current_nanos = self.current_nanos?;
+        if self.baseline_nanos == 0.0 {
+            return Some(0.0);
+        }
+        Some((current_nanos - self.baseline_nanos) / self.baseline_nanos * 100.0)
+    }
+
+    /// A part regressed if it is slower by more than `threshold_percent`, or missing from the current run.
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.change_percent()
+            .is_none_or(|change| change > threshold_percent)
+    }
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parse a duration string in the format of `Duration`'s debug output into nanoseconds.
+fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s {
+        s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
     }
 }
 
@@ -113,6 +243,16 @@ impl From<&Timing> for JsonValue {
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+        map.insert("timed_out".into(), JsonValue::Boolean(value.timed_out));
+        map.insert("failed".into(), JsonValue::Boolean(value.failed));
+
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
 
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
@@ -133,6 +273,57 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "parse_stats".into(),
+            value.parse_stats.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_1_stats".into(),
+            value.part_1_stats.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value.part_2_stats.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "parse_heap".into(),
+            value.parse_heap.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_1_heap".into(),
+            value.part_1_heap.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_heap".into(),
+            value.part_2_heap.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "parse_counters".into(),
+            value
+                .parse_counters
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_1_counters".into(),
+            value
+                .part_1_counters
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_counters".into(),
+            value
+                .part_2_counters
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -166,10 +357,67 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: parse times, stats, heap stats and counters are optional to stay compatible with timings stored by older versions.
+        let parse = match json.get("parse") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.parse to be null or string.")?
+                    .clone(),
+            ),
+            _ => None,
+        };
+
+        let parse_stats = match json.get("parse_stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let part_1_stats = match json.get("part_1_stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let part_2_stats = match json.get("part_2_stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let heap = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => HeapStats::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
+        let counters = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => PerfCounters::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
+        let timed_out = json
+            .get("timed_out")
+            .and_then(|v| v.get::<bool>().copied())
+            .unwrap_or(false);
+
+        let failed = json
+            .get("failed")
+            .and_then(|v| v.get::<bool>().copied())
+            .unwrap_or(false);
+
         Ok(Timing {
             day,
+            parse,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            parse_stats,
+            part_1_stats,
+            part_2_stats,
+            parse_heap: heap("parse_heap")?,
+            part_1_heap: heap("part_1_heap")?,
+            part_2_heap: heap("part_2_heap")?,
+            parse_counters: counters("parse_counters")?,
+            part_1_counters: counters("part_1_counters")?,
+            part_2_counters: counters("part_2_counters")?,
+            timed_out,
+            failed,
             total_nanos,
         })
     }
@@ -177,6 +425,158 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+#[allow(clippy::cast_precision_loss)]
+impl From<BenchStats> for JsonValue {
+    fn from(value: BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let durations = [
+            ("cold_nanos", value.cold),
+            ("min_nanos", value.min),
+            ("median_nanos", value.median),
+            ("mean_nanos", value.mean),
+            ("p95_nanos", value.p95),
+            ("stddev_nanos", value.stddev),
+        ];
+
+        for (key, duration) in durations {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            cold: duration("cold_nanos")?,
+            min: duration("min_nanos")?,
+            median: duration("median_nanos")?,
+            mean: duration("mean_nanos")?,
+            p95: duration("p95_nanos")?,
+            stddev: duration("stddev_nanos")?,
+            outliers: number("outliers")? as usize,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<HeapStats> for JsonValue {
+    fn from(value: HeapStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+        map.insert(
+            "total_allocations".into(),
+            JsonValue::Number(value.total_allocations as f64),
+        );
+        map.insert(
+            "total_bytes".into(),
+            JsonValue::Number(value.total_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HeapStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected heap stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|x| x as u64)
+                .ok_or(format!("Expected heap stats.{key} to be a number."))
+        };
+
+        Ok(HeapStats {
+            peak_bytes: number("peak_bytes")?,
+            total_allocations: number("total_allocations")?,
+            total_bytes: number("total_bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<PerfCounters> for JsonValue {
+    fn from(value: PerfCounters) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let counts = [
+            ("instructions", value.instructions),
+            ("cycles", value.cycles),
+            ("cache_misses", value.cache_misses),
+            ("branch_misses", value.branch_misses),
+        ];
+
+        for (key, count) in counts {
+            map.insert(key.into(), JsonValue::Number(count as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PerfCounters {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected counters to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u64)
+                .ok_or(format!("Expected counters.{key} to be a number."))
+        };
+
+        Ok(PerfCounters {
+            instructions: number("instructions")?,
+            cycles: number("cycles")?,
+            cache_misses: number("cache_misses")?,
+            branch_misses: number("branch_misses")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
@@ -187,22 +587,21 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
-                    part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -210,6 +609,7 @@ mod tests {
 
     mod deserialization {
         use crate::{day, template::timings::Timings};
+        use std::time::Duration;
 
         #[test]
         fn handles_json_timings() {
@@ -223,6 +623,28 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timing_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "samples": 10, "cold_nanos": 5000000, "min_nanos": 900000, "median_nanos": 1000000, "mean_nanos": 1100000, "p95_nanos": 1500000, "stddev_nanos": 20000, "outliers": 1 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.cold, Duration::from_millis(5));
+            assert_eq!(stats.median, Duration::from_millis(1));
+            assert_eq!(stats.outliers, 1);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_json_parse_timings() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "2ms", "part_1": "1ms", "part_2": null, "total_nanos": 3000000, "parse_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse, Some("2ms".to_string()));
+            assert_eq!(timing.parse_stats, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,7 +669,14 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
-        use std::collections::HashMap;
+        use crate::{
+            day,
+            template::{
+                runner::{BenchStats, HeapStats, PerfCounters},
+                timings::Timings,
+            },
+        };
+        use std::{collections::HashMap, time::Duration};
         use tinyjson::JsonValue;
 
         #[test]
@@ -266,6 +695,52 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn round_trips_timing_stats() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1_stats = Some(BenchStats::from_samples(
+                Duration::from_millis(3),
+                &[Duration::from_micros(10), Duration::from_micros(12)],
+            ));
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            let timing = parsed.data.iter().find(|t| t.day == day!(1)).unwrap();
+            assert_eq!(timing.part_1_stats, timings.data[0].part_1_stats);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn round_trips_heap_stats() {
+            let mut timings = get_mock_timings();
+            timings.data[1].part_2_heap = Some(HeapStats {
+                peak_bytes: 2048,
+                total_allocations: 17,
+                total_bytes: 4096,
+            });
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            let timing = parsed.data.iter().find(|t| t.day == day!(2)).unwrap();
+            assert_eq!(timing.part_2_heap, timings.data[1].part_2_heap);
+            assert_eq!(timing.part_1_heap, None);
+            assert_eq!(timing.parse_heap, None);
+        }
+
+        #[test]
+        fn round_trips_counters() {
+            let mut timings = get_mock_timings();
+            timings.data[0].parse_counters = Some(PerfCounters {
+                instructions: 123_456_789,
+                cycles: 98_765_432,
+                cache_misses: 1_024,
+                branch_misses: 77,
+            });
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            let timing = parsed.data.iter().find(|t| t.day == day!(1)).unwrap();
+            assert_eq!(timing.parse_counters, timings.data[0].parse_counters);
+            assert_eq!(timing.part_1_counters, None);
+        }
     }
 
     mod is_day_complete {
@@ -278,10 +753,10 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
@@ -292,10 +767,9 @@ mod tests {
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
-                    part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
@@ -303,20 +777,101 @@ mod tests {
         }
 
         #[test]
-        fn handles_uncompleted_days() {
+        fn handles_last_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
+                    part_1: Some("1ms".into()),
+                    total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(2023 / 25))
                 }],
             };
 
+            assert_eq!(timings.is_day_complete(&day!(2023 / 25)), true);
+        }
+
+        #[test]
+        fn handles_uncompleted_days() {
+            let timings = Timings {
+                data: vec![Timing::new(day!(1))],
+            };
+
             assert_eq!(timings.is_day_complete(&day!(1)), false);
         }
     }
 
+    mod compare {
+        use crate::{
+            day,
+            template::{runner::BenchStats, timings::Timings, Day},
+        };
+        use std::{collections::HashSet, time::Duration};
+
+        use super::get_mock_timings;
+
+        fn days(timings: &Timings) -> HashSet<Day> {
+            timings.data.iter().map(|t| t.day).collect()
+        }
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let baseline = get_mock_timings();
+            let mut current = get_mock_timings();
+            current.data[0].part_1 = Some("12ms".into());
+            current.data[0].part_2 = Some("15ms".into());
+            current.data.remove(1);
+
+            let deltas = current.compare(&baseline, &days(&current));
+
+            assert_eq!(deltas.len(), 3);
+            assert_eq!(deltas[0].day, day!(1));
+            assert_eq!(deltas[0].part, 1);
+            assert_eq!(deltas[0].change_percent().unwrap().round(), 20.0);
+            assert_eq!(deltas[0].is_regression(10.0), true);
+            assert_eq!(deltas[1].change_percent().unwrap().round(), -25.0);
+            assert_eq!(deltas[1].is_regression(10.0), false);
+            assert_eq!(deltas[2].day, day!(4));
+            assert_eq!(deltas[2].change_percent(), Some(0.0));
+        }
+
+        #[test]
+        fn reports_missing_parts_as_regressions() {
+            let baseline = get_mock_timings();
+            let mut current = get_mock_timings();
+            current.data[0].part_2 = None;
+            current.data.remove(1);
+
+            let deltas = current.compare(&baseline, &days(&baseline));
+
+            assert_eq!(deltas.len(), 5);
+            assert_eq!((deltas[1].day, deltas[1].part), (day!(1), 2));
+            assert_eq!(deltas[1].current_nanos, None);
+            assert_eq!(deltas[1].is_regression(10.0), true);
+            assert_eq!((deltas[2].day, deltas[3].day), (day!(2), day!(2)));
+            assert_eq!(deltas[3].current_nanos, None);
+            assert_eq!(deltas[4].is_regression(10.0), false);
+        }
+
+        #[test]
+        fn prefers_stats_over_duration_strings() {
+            let baseline = get_mock_timings();
+            let mut current = get_mock_timings();
+            current.data[0].part_1_stats = Some(BenchStats::single(Duration::from_millis(11)));
+
+            let deltas = current.compare(&baseline, &days(&current));
+
+            assert_eq!(deltas[0].current_nanos, Some(11_000_000.0));
+            assert_eq!(deltas[0].is_regression(15.0), false);
+            assert_eq!(deltas[0].is_regression(5.0), true);
+        }
+
+        #[test]
+        fn handles_empty_baseline() {
+            let current = get_mock_timings();
+            let deltas = current.compare(&Timings::default(), &days(&current));
+            assert_eq!(deltas.len(), 0);
+        }
+    }
+
     mod merge {
         use crate::{
             day,
@@ -329,12 +884,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(3))],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,12 +899,7 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(2))],
             };
             let merged = timings.merge(&other);
 
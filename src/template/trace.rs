@@ -0,0 +1,146 @@
+/// Module behind the [`debug!`](crate::debug) and [`trace!`](crate::trace) macros, which print debug output of a solution.
+///
+/// Output is enabled with `cargo solve NN -v` (debug) or `-vv` (debug and trace) and written to stderr,
+/// so it does not mix with the answers on stdout. With `--log` it is written to `target/log/{day}.log` instead,
+/// `--log` without `-vv` implies `-v`.
+/// Benching runs a part without output, see [`Muted`].
+use std::{
+    env,
+    fmt::Arguments,
+    fs::{self, File},
+    io::{self, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, OnceLock,
+    },
+};
+
+use crate::template::Day;
+
+/// How detailed a message is. A message is written if the verbosity is at least its level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug = 1,
+    Trace = 2,
+}
+
+impl Level {
+    fn as_str(self) -> &'static str {
+        match self {
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+struct Sink {
+    verbosity: u8,
+    /// The log file, or `None` for stderr.
+    file: Option<Mutex<File>>,
+}
+
+static SINK: OnceLock<Sink> = OnceLock::new();
+
+static MUTED: AtomicBool = AtomicBool::new(false);
+
+/// Read the verbosity and output from the arguments. Called by [`solution!`](crate::solution) before running a day.
+pub fn init(day: Day) {
+    let args: Vec<String> = env::args().collect();
+    let verbosity = verbosity_from_args(&args);
+
+    let file = args
+        .iter()
+        .any(|x| x == "--log")
+        .then(|| {
+            let path = format!("target/log/{day}.log");
+            fs::create_dir_all(Path::new(&path).parent().unwrap_or(Path::new("target/log")))
+                .and_then(|()| File::create(&path))
+                .inspect(|_| eprintln!("Writing debug output to \"{path}\"."))
+                .inspect_err(|e| eprintln!("Failed to create log \"{path}\": {e}"))
+                .ok()
+        })
+        .flatten()
+        .map(Mutex::new);
+
+    let _ = SINK.set(Sink { verbosity, file });
+}
+
+/// The verbosity requested by `-v` or `-vv`. `--log` requests at least `-v`.
+pub fn verbosity_from_args(args: &[String]) -> u8 {
+    let verbosity = args
+        .iter()
+        .filter_map(|arg| {
+            let flags = arg.strip_prefix('-')?;
+            (!flags.is_empty() && flags.chars().all(|c| c == 'v')).then_some(flags.len())
+        })
+        .sum::<usize>()
+        .min(Level::Trace as usize) as u8;
+
+    if args.iter().any(|x| x == "--log") {
+        verbosity.max(Level::Debug as u8)
+    } else {
+        verbosity
+    }
+}
+
+/// Whether messages of `level` are written. The macros check this before formatting a message.
+pub fn enabled(level: Level) -> bool {
+    !MUTED.load(Ordering::Relaxed) && SINK.get().is_some_and(|sink| sink.verbosity >= level as u8)
+}
+
+/// Write a message, use the macros instead.
+pub fn write(level: Level, message: Arguments) {
+    let Some(sink) = SINK.get() else {
+        return;
+    };
+
+    let line = format!("[{}] {message}\n", level.as_str());
+
+    let _ = match &sink.file {
+        Some(file) => file
+            .lock()
+            .map_or(Ok(()), |mut file| file.write_all(line.as_bytes())),
+        None => io::stderr().lock().write_all(line.as_bytes()),
+    };
+}
+
+/// Suppresses all messages while it is alive, used while benching.
+pub(crate) struct Muted {
+    previous: bool,
+}
+
+impl Muted {
+    pub(crate) fn new() -> Self {
+        Muted {
+            previous: MUTED.swap(true, Ordering::Relaxed),
+        }
+    }
+}
+
+impl Drop for Muted {
+    fn drop(&mut self) {
+        MUTED.store(self.previous, Ordering::Relaxed);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{verbosity_from_args, Level};
+
+    #[test]
+    fn reads_verbosity() {
+        let verbosity = |args: &[&str]| {
+            verbosity_from_args(&args.iter().map(ToString::to_string).collect::<Vec<_>>())
+        };
+
+        assert_eq!(verbosity(&["05", "--time"]), 0);
+        assert_eq!(verbosity(&["05", "-v"]), Level::Debug as u8);
+        assert_eq!(verbosity(&["05", "-vv"]), Level::Trace as u8);
+        assert_eq!(verbosity(&["05", "-v", "-v", "-vvv"]), Level::Trace as u8);
+        assert_eq!(verbosity(&["05", "--log"]), Level::Debug as u8);
+        assert_eq!(verbosity(&["05", "-vv", "--log"]), Level::Trace as u8);
+    }
+}
@@ -0,0 +1,481 @@
+//! Module that lets a solution emit frames of a grid, e.g. the state of a simulation after every step.
+//!
+//! Frames are only recorded with the `viz` feature and the `--viz` argument, which `cargo solve NN --viz` passes.
+//! Without the feature every function is a no-op, so benchmarks are not affected.
+//! Frames are only captured while the part runs, they are played back or written once its time has been taken.
+//!
+//! By default frames are played back in the terminal with `--viz-fps` frames per second (default 10).
+//! With `--viz-format ppm|png` every frame is written to `target/viz/{day}/part-{part}/`,
+//! with `--viz-format gif` all frames of a part are written to `target/viz/{day}-part-{part}.gif`.
+
+#[cfg(not(feature = "viz"))]
+use crate::template::Day;
+
+/// Whether frames are recorded. Use it to skip work that is only needed for frames.
+#[cfg(not(feature = "viz"))]
+#[inline]
+pub fn enabled() -> bool {
+    false
+}
+
+/// Emit a frame of `width` × `height` cells. `cell` returns the character of a cell.
+///
+/// In images, `#` is drawn white, `.` and spaces are drawn black, other characters get a color of their own.
+#[cfg(not(feature = "viz"))]
+#[inline]
+pub fn frame(_width: usize, _height: usize, _cell: impl Fn(usize, usize) -> char) {}
+
+/// Start recording the frames of a part. Called by the runner.
+#[cfg(not(feature = "viz"))]
+#[inline]
+pub fn begin(_day: Day, _part: u8) {}
+
+/// Finish recording the frames of a part. Called by the runner.
+#[cfg(not(feature = "viz"))]
+#[inline]
+pub fn end() {}
+
+#[cfg(feature = "viz")]
+pub use recorder::{begin, enabled, end, frame};
+
+#[cfg(feature = "viz")]
+mod recorder {
+    use std::{
+        cell::RefCell,
+        env, fs,
+        io::{self, stdout, Write},
+        path::PathBuf,
+        thread,
+        time::Duration,
+    };
+
+    use super::{encode, Format};
+    use crate::template::Day;
+
+    /// Size of a cell in images, in pixels.
+    const SCALE: usize = 4;
+
+    struct Recorder {
+        day: Day,
+        part: u8,
+        format: Format,
+        fps: u32,
+        frames: Vec<Frame>,
+    }
+
+    /// A captured frame, rendered once the part has finished.
+    struct Frame {
+        width: usize,
+        height: usize,
+        /// The cells, one line per row.
+        text: String,
+    }
+
+    impl Frame {
+        fn image(&self) -> encode::Image {
+            let rows: Vec<Vec<char>> = self.text.lines().map(|l| l.chars().collect()).collect();
+            encode::Image::from_cells(self.width, self.height, SCALE, |x, y| rows[y][x])
+        }
+    }
+
+    thread_local! {
+        static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+    }
+
+    pub fn enabled() -> bool {
+        RECORDER.with_borrow(Option::is_some)
+    }
+
+    pub fn begin(day: Day, part: u8) {
+        let args: Vec<String> = env::args().collect();
+
+        if !args.iter().any(|x| x == "--viz") || args.iter().any(|x| x == "--time") {
+            return;
+        }
+
+        let value = |flag: &str| {
+            let index = args.iter().position(|x| x == flag)? + 1;
+            args.get(index).cloned()
+        };
+
+        let format = match value("--viz-format").as_deref() {
+            None | Some("terminal") => Format::Terminal,
+            Some("ppm") => Format::Ppm,
+            Some("png") => Format::Png,
+            Some("gif") => Format::Gif,
+            Some(format) => {
+                eprintln!(
+                    "Unknown visualization format `{format}`, expected terminal, ppm, png or gif."
+                );
+                return;
+            }
+        };
+
+        let fps = value("--viz-fps")
+            .and_then(|x| x.parse().ok())
+            .unwrap_or(10)
+            .max(1);
+
+        RECORDER.set(Some(Recorder {
+            day,
+            part,
+            format,
+            fps,
+            frames: vec![],
+        }));
+    }
+
+    pub fn frame(width: usize, height: usize, cell: impl Fn(usize, usize) -> char) {
+        RECORDER.with_borrow_mut(|recorder| {
+            let Some(recorder) = recorder else {
+                return;
+            };
+
+            let mut text = String::with_capacity((width + 1) * height);
+            for y in 0..height {
+                text.extend((0..width).map(|x| cell(x, y)));
+                text.push('\n');
+            }
+
+            recorder.frames.push(Frame {
+                width,
+                height,
+                text,
+            });
+        });
+    }
+
+    pub fn end() {
+        let Some(recorder) = RECORDER.take() else {
+            return;
+        };
+
+        match recorder.finish() {
+            Ok(Some(path)) => {
+                println!(
+                    "Wrote {} frames to \"{}\".",
+                    recorder.frames.len(),
+                    path.display()
+                );
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("Failed to write frames: {e}"),
+        }
+    }
+
+    impl Recorder {
+        fn dir(&self) -> PathBuf {
+            PathBuf::from("target")
+                .join("viz")
+                .join(self.day.to_string())
+        }
+
+        /// Play back or write the captured frames, returns the path of the output.
+        fn finish(&self) -> io::Result<Option<PathBuf>> {
+            if self.frames.is_empty() {
+                return Ok(None);
+            }
+
+            match self.format {
+                Format::Terminal => {
+                    let mut stdout = stdout().lock();
+                    for (i, frame) in self.frames.iter().enumerate() {
+                        writeln!(stdout, "\x1b[H\x1b[2J{}Frame {}", frame.text, i + 1)?;
+                        stdout.flush()?;
+                        thread::sleep(Duration::from_secs(1) / self.fps);
+                    }
+                    Ok(None)
+                }
+                Format::Ppm | Format::Png => {
+                    let dir = self.dir().join(format!("part-{}", self.part));
+                    fs::create_dir_all(&dir)?;
+
+                    for (i, frame) in self.frames.iter().enumerate() {
+                        let image = frame.image();
+                        let (bytes, extension) = if self.format == Format::Ppm {
+                            (encode::ppm(&image), "ppm")
+                        } else {
+                            (encode::png(&image), "png")
+                        };
+                        fs::write(dir.join(format!("frame-{:05}.{extension}", i + 1)), bytes)?;
+                    }
+
+                    Ok(Some(dir))
+                }
+                Format::Gif => {
+                    let delay = u16::try_from(100 / self.fps).unwrap_or(u16::MAX).max(1);
+                    let path = PathBuf::from("target")
+                        .join("viz")
+                        .join(format!("{}-part-{}.gif", self.day, self.part));
+
+                    let width = self.frames.iter().map(|f| f.width).max().unwrap_or(0);
+                    let height = self.frames.iter().map(|f| f.height).max().unwrap_or(0);
+                    let gif = encode::gif(
+                        width * SCALE,
+                        height * SCALE,
+                        self.frames.iter().map(Frame::image),
+                        delay,
+                    );
+
+                    fs::create_dir_all(path.parent().unwrap_or(&self.dir()))?;
+                    fs::write(&path, gif)?;
+                    Ok(Some(path))
+                }
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "viz"), allow(dead_code))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Terminal,
+    Ppm,
+    Png,
+    Gif,
+}
+
+/// Encoders of the image formats, without any dependencies.
+#[cfg_attr(not(feature = "viz"), allow(dead_code))]
+mod encode {
+    /// An image with a palette of 256 colors, see [`color`].
+    pub struct Image {
+        pub width: usize,
+        pub height: usize,
+        /// Color indices, row by row.
+        pub pixels: Vec<u8>,
+    }
+
+    impl Image {
+        /// Draw every cell as a square of `scale` pixels.
+        pub fn from_cells(
+            width: usize,
+            height: usize,
+            scale: usize,
+            cell: impl Fn(usize, usize) -> char,
+        ) -> Self {
+            let mut pixels = Vec::with_capacity(width * height * scale * scale);
+
+            for y in 0..height {
+                let row: Vec<u8> = (0..width)
+                    .map(|x| u8::try_from(cell(x, y)).unwrap_or(b'?'))
+                    .flat_map(|c| std::iter::repeat_n(c, scale))
+                    .collect();
+
+                for _ in 0..scale {
+                    pixels.extend_from_slice(&row);
+                }
+            }
+
+            Image {
+                width: width * scale,
+                height: height * scale,
+                pixels,
+            }
+        }
+
+        fn rgb(&self) -> impl Iterator<Item = u8> + '_ {
+            self.pixels.iter().flat_map(|&c| color(c))
+        }
+    }
+
+    /// The color of a character.
+    pub fn color(c: u8) -> [u8; 3] {
+        const COLORS: [[u8; 3]; 6] = [
+            [230, 57, 70],
+            [69, 123, 157],
+            [244, 162, 97],
+            [42, 157, 143],
+            [233, 196, 106],
+            [168, 218, 220],
+        ];
+
+        match c {
+            b'#' => [255, 255, 255],
+            b'.' | b' ' => [16, 16, 16],
+            c => COLORS[usize::from(c) % COLORS.len()],
+        }
+    }
+
+    pub fn ppm(image: &Image) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", image.width, image.height).into_bytes();
+        out.extend(image.rgb());
+        out
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn png(image: &Image) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((image.width * 3 + 1) * image.height);
+        let rgb: Vec<u8> = image.rgb().collect();
+        for row in rgb.chunks(image.width * 3) {
+            raw.push(0);
+            raw.extend_from_slice(row);
+        }
+
+        // zlib stream of uncompressed deflate blocks.
+        let mut zlib = vec![0x78, 0x01];
+        let blocks: Vec<&[u8]> = raw.chunks(u16::MAX as usize).collect();
+        for (i, block) in blocks.iter().enumerate() {
+            let len = block.len() as u16;
+            zlib.push(u8::from(i + 1 == blocks.len()));
+            zlib.extend_from_slice(&len.to_le_bytes());
+            zlib.extend_from_slice(&(!len).to_le_bytes());
+            zlib.extend_from_slice(block);
+        }
+        zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+        let mut header = vec![];
+        header.extend_from_slice(&(image.width as u32).to_be_bytes());
+        header.extend_from_slice(&(image.height as u32).to_be_bytes());
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut out = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        for (kind, data) in [(b"IHDR", &header), (b"IDAT", &zlib), (b"IEND", &vec![])] {
+            out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            let start = out.len();
+            out.extend_from_slice(kind);
+            out.extend_from_slice(data);
+            let crc = crc32(&out[start..]);
+            out.extend_from_slice(&crc.to_be_bytes());
+        }
+
+        out
+    }
+
+    /// An animated GIF of `width` × `height` pixels that loops forever.
+    /// `delay` is the time between frames in hundredths of a second.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn gif(
+        width: usize,
+        height: usize,
+        frames: impl IntoIterator<Item = Image>,
+        delay: u16,
+    ) -> Vec<u8> {
+        let (width, height) = (width as u16, height as u16);
+
+        let mut out = b"GIF89a".to_vec();
+        out.extend_from_slice(&width.to_le_bytes());
+        out.extend_from_slice(&height.to_le_bytes());
+        out.extend_from_slice(&[0xf7, 0, 0]);
+        out.extend((0..=255).flat_map(color));
+        out.extend_from_slice(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+        for frame in frames {
+            out.extend_from_slice(&[0x21, 0xf9, 0x04, 0x00]);
+            out.extend_from_slice(&delay.to_le_bytes());
+            out.extend_from_slice(&[0x00, 0x00]);
+
+            out.push(0x2c);
+            out.extend_from_slice(&[0, 0, 0, 0]);
+            out.extend_from_slice(&(frame.width as u16).to_le_bytes());
+            out.extend_from_slice(&(frame.height as u16).to_le_bytes());
+            out.push(0);
+
+            out.push(8);
+            for block in lzw_uncompressed(&frame.pixels).chunks(255) {
+                out.push(block.len() as u8);
+                out.extend_from_slice(block);
+            }
+            out.push(0);
+        }
+
+        out.push(0x3b);
+        out
+    }
+
+    /// LZW data that only uses literal codes. A clear code is sent before the code size would grow past 9 bits.
+    fn lzw_uncompressed(pixels: &[u8]) -> Vec<u8> {
+        const CLEAR: u32 = 256;
+        const END: u32 = 257;
+
+        let mut out = vec![];
+        let (mut buffer, mut bits) = (0_u32, 0);
+
+        let mut write = |code: u32| {
+            buffer |= code << bits;
+            bits += 9;
+            while bits >= 8 {
+                out.push((buffer & 0xff) as u8);
+                buffer >>= 8;
+                bits -= 8;
+            }
+        };
+
+        for chunk in pixels.chunks(250) {
+            write(CLEAR);
+            chunk.iter().for_each(|&p| write(u32::from(p)));
+        }
+        write(END);
+
+        if bits > 0 {
+            out.push((buffer & 0xff) as u8);
+        }
+
+        out
+    }
+
+    fn crc32(data: &[u8]) -> u32 {
+        let mut crc = 0xffff_ffff_u32;
+        for &byte in data {
+            crc ^= u32::from(byte);
+            for _ in 0..8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ 0xedb8_8320
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+        !crc
+    }
+
+    fn adler32(data: &[u8]) -> u32 {
+        let (mut a, mut b) = (1_u32, 0_u32);
+        for &byte in data {
+            a = (a + u32::from(byte)) % 65521;
+            b = (b + a) % 65521;
+        }
+        (b << 16) | a
+    }
+
+    /* ---------------------------------------------------------------------- */
+
+    #[cfg(feature = "test_lib")]
+    mod tests {
+        use super::{adler32, crc32, gif, png, ppm, Image};
+
+        fn image() -> Image {
+            Image::from_cells(2, 1, 2, |x, _| if x == 0 { '#' } else { '.' })
+        }
+
+        #[test]
+        fn scales_cells() {
+            let image = image();
+            assert_eq!((image.width, image.height), (4, 2));
+            assert_eq!(image.pixels, b"##..##..");
+        }
+
+        #[test]
+        fn computes_checksums() {
+            assert_eq!(crc32(b"IEND"), 0xae42_6082);
+            assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        }
+
+        #[test]
+        fn encodes_images() {
+            let image = image();
+
+            let ppm = ppm(&image);
+            assert!(ppm.starts_with(b"P6\n4 2\n255\n"));
+            assert_eq!(&ppm[11..17], &[255, 255, 255, 255, 255, 255]);
+
+            let png = png(&image);
+            assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR"));
+            assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+
+            let gif = gif(image.width, image.height, [image], 10);
+            assert!(gif.starts_with(b"GIF89a\x04\x00\x02\x00"));
+            assert_eq!(gif.last(), Some(&0x3b));
+        }
+    }
+}